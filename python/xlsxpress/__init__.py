@@ -29,6 +29,15 @@ Examples:
         sheet = writer.add_worksheet("Results")
         writer.write_string(sheet, 0, 0, "Hello World")
         writer.save("output.xlsx")
+
+    Writing with a context manager (saves on exit)::
+
+        import xlsxpress
+
+        with xlsxpress.Writer() as writer:
+            writer.set_path("output.xlsx")
+            sheet = writer.add_worksheet("Results")
+            writer.write_string(sheet, 0, 0, "Hello World")
 """
 
 __version__ = "0.1.0"
@@ -41,39 +50,62 @@ from .xlsxpress import (
     Reader,
     Writer,
     Worksheet,
+    # Style classes
+    Font,
+    Fill,
+    Border,
+    Alignment,
+    NumberFormat,
+    Style,
+    # Chart classes
+    LineChart,
+    ColumnChart,
+    BarChart,
+    PieChart,
+    ScatterChart,
+    AreaChart,
+    DoughnutChart,
+    DataSeries,
+    ChartPosition,
+    # Validation classes
+    ListValidation,
+    NumberValidation,
+    DateValidation,
+    TextValidation,
+    ValidationError,
+    ValidationWarning,
+    DataValidation,
 )
 
-# TODO: Add advanced features after fixing compilation errors
-# # Chart classes
-# LineChart,
-# ColumnChart,
-# BarChart,
-# PieChart,
-# ScatterChart,
-# AreaChart,
-# DoughnutChart,
-# DataSeries,
-# ChartPosition,
-# # Style classes
-# Font,
-# Fill,
-# Border,
-# Alignment,
-# NumberFormat,
-# Style,
-# # Validation classes
-# ListValidation,
-# NumberValidation,
-# DateValidation,
-# TextValidation,
-# ValidationError,
-# ValidationWarning,
-# DataValidation,
-
 __all__ = [
     "__version__",
     # Core classes
     "Reader",
     "Writer",
     "Worksheet",
+    # Style classes
+    "Font",
+    "Fill",
+    "Border",
+    "Alignment",
+    "NumberFormat",
+    "Style",
+    # Chart classes
+    "LineChart",
+    "ColumnChart",
+    "BarChart",
+    "PieChart",
+    "ScatterChart",
+    "AreaChart",
+    "DoughnutChart",
+    "DataSeries",
+    "ChartPosition",
+    # Validation classes
+    "ListValidation",
+    "NumberValidation",
+    "DateValidation",
+    "TextValidation",
+    "ValidationError",
+    "ValidationWarning",
+    "DataValidation",
 ]
@@ -0,0 +1,268 @@
+//! Cell styles
+//!
+//! Rebuilds a [`Style`] for each formatted cell from the fonts, fills and
+//! number formats in the workbook's `xl/styles.xml`, so styled workbooks can
+//! be read, edited and written back.
+
+use crate::error::{Error, Result};
+use crate::number_formats::{cell_style_indexes, custom_formats, format_code};
+use crate::package::{element_attributes, Package};
+use crate::styles::{Fill, Font, Style};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader as XmlReader;
+use std::collections::HashMap;
+
+/// Style components referenced by one `cellXfs` entry
+struct CellXf {
+    /// Number format ID
+    num_fmt: u32,
+    /// Index into the fonts list
+    font: usize,
+    /// Index into the fills list
+    fill: usize,
+}
+
+/// Fonts, fills and cell formats of `xl/styles.xml`
+#[derive(Default)]
+struct StyleSheet {
+    /// Fonts in index order
+    fonts: Vec<Font>,
+    /// Fills in index order; `None` for fills without a solid color
+    fills: Vec<Option<Fill>>,
+    /// Cell formats in index order
+    cell_xfs: Vec<CellXf>,
+    /// Whether the fill being parsed has a solid pattern
+    solid_fill: bool,
+}
+
+/// Read the style of every formatted cell on a worksheet
+///
+/// Keys are zero-based `(row, col)`. Fonts other than the workbook default,
+/// solid fills and number formats are reconstructed; cells whose style has
+/// none of these are left out.
+///
+/// # Errors
+///
+/// Returns `Error::SheetNotFound` if the sheet doesn't exist.
+/// Returns `Error::InvalidFormat` if the package or its parts are malformed.
+pub(crate) fn read_cell_styles(
+    package: &mut Package,
+    sheet: &str,
+) -> Result<HashMap<(u32, u32), Style>> {
+    let sheet_part = package.sheet_part(sheet)?;
+    let Some(styles) = package.read_text("xl/styles.xml")? else {
+        return Ok(HashMap::new());
+    };
+    let styles = cell_xf_styles(&styles)?;
+    let worksheet = package.require_text(&sheet_part)?;
+
+    Ok(cell_style_indexes(&worksheet)?
+        .into_iter()
+        .filter_map(|(position, xf)| {
+            let style = styles.get(xf).filter(|style| **style != Style::new())?;
+            Some((position, style.clone()))
+        })
+        .collect())
+}
+
+/// Style of each cell format (`cellXfs` entry), in index order
+fn cell_xf_styles(styles: &str) -> Result<Vec<Style>> {
+    let custom = custom_formats(styles)?;
+    let sheet = parse_style_sheet(styles)?;
+
+    Ok(sheet
+        .cell_xfs
+        .iter()
+        .map(|xf| {
+            let mut style = Style::from_number_format_string(&format_code(xf.num_fmt, &custom));
+            if xf.font != 0 {
+                if let Some(font) = sheet.fonts.get(xf.font) {
+                    style = style.font(font.clone());
+                }
+            }
+            if let Some(Some(fill)) = sheet.fills.get(xf.fill) {
+                style = style.fill(fill.clone());
+            }
+            style
+        })
+        .collect())
+}
+
+/// Collect the fonts, fills and cell formats of a style sheet
+fn parse_style_sheet(styles: &str) -> Result<StyleSheet> {
+    let mut reader = XmlReader::from_str(styles);
+    let mut sheet = StyleSheet::default();
+    let mut section = Vec::new();
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                sheet.add_element(&e, &reader, &section);
+                section.push(e.local_name().as_ref().to_vec());
+            }
+            Ok(Event::Empty(e)) => sheet.add_element(&e, &reader, &section),
+            Ok(Event::End(_)) => {
+                section.pop();
+            }
+            Ok(Event::Eof) => return Ok(sheet),
+            Err(e) => return Err(Error::invalid_format(format!("invalid styles XML: {e}"))),
+            Ok(_) => {}
+        }
+    }
+}
+
+impl StyleSheet {
+    /// Apply one element, given the names of its enclosing elements
+    fn add_element(&mut self, e: &BytesStart<'_>, reader: &XmlReader<&[u8]>, section: &[Vec<u8>]) {
+        // Differential formats reuse <font> and <fill> but aren't cell styles
+        if section.iter().any(|name| name == b"dxfs") {
+            return;
+        }
+        let parent = section.last().map(Vec::as_slice);
+        let attrs = element_attributes(e, reader);
+        match (parent, e.local_name().as_ref()) {
+            (Some(b"fonts"), b"font") => self.fonts.push(Font::new()),
+            (Some(b"font"), property) => {
+                if let Some(font) = self.fonts.pop() {
+                    self.fonts.push(font_property(font, property, &attrs));
+                }
+            }
+            (Some(b"fills"), b"fill") => self.fills.push(None),
+            (Some(b"fill"), b"patternFill") => {
+                self.solid_fill = attrs.get("patternType").map(String::as_str) == Some("solid");
+            }
+            (Some(b"patternFill"), b"fgColor") if self.solid_fill => {
+                if let (Some(fill), Some(rgb)) = (self.fills.last_mut(), attrs.get("rgb")) {
+                    *fill = Some(Fill::solid(rgb_hex(rgb)));
+                }
+            }
+            (Some(b"cellXfs"), b"xf") => {
+                let index = |key: &str| attrs.get(key).and_then(|id| id.parse().ok());
+                self.cell_xfs.push(CellXf {
+                    num_fmt: attrs
+                        .get("numFmtId")
+                        .and_then(|id| id.parse().ok())
+                        .unwrap_or(0),
+                    font: index("fontId").unwrap_or(0),
+                    fill: index("fillId").unwrap_or(0),
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Apply one child element of a `<font>` to a font
+fn font_property(font: Font, property: &[u8], attrs: &HashMap<String, String>) -> Font {
+    let value = attrs.get("val");
+    let enabled = !matches!(value.map(String::as_str), Some("0" | "false"));
+    match property {
+        b"b" => font.bold(enabled),
+        b"i" => font.italic(enabled),
+        b"sz" => match value.and_then(|size| size.parse().ok()) {
+            Some(size) => font.size(size),
+            None => font,
+        },
+        b"name" => match value {
+            Some(name) => font.name(name.as_str()),
+            None => font,
+        },
+        b"color" => match attrs.get("rgb") {
+            Some(rgb) => font.color(rgb_hex(rgb)),
+            None => font,
+        },
+        _ => font,
+    }
+}
+
+/// `#RRGGBB` color from an `ARGB` or `RGB` hex attribute
+fn rgb_hex(argb: &str) -> String {
+    let rgb = argb.get(argb.len().saturating_sub(6)..).unwrap_or(argb);
+    format!("#{rgb}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::styles::NumberFormat;
+
+    /// TDD RED: Test cell formats rebuild fonts, solid fills and number formats
+    #[test]
+    fn test_cell_xf_styles() {
+        let styles = r#"<styleSheet>
+            <numFmts count="1"><numFmt numFmtId="164" formatCode="$#,##0.00"/></numFmts>
+            <fonts count="2">
+                <font><sz val="11"/><name val="Calibri"/></font>
+                <font><b/><sz val="12"/><color rgb="FFFF0000"/><name val="Arial"/></font>
+            </fonts>
+            <fills count="3">
+                <fill><patternFill patternType="none"/></fill>
+                <fill><patternFill patternType="gray125"/></fill>
+                <fill><patternFill patternType="solid"><fgColor rgb="FFFFFF00"/></patternFill></fill>
+            </fills>
+            <cellStyleXfs count="1"><xf numFmtId="0" fontId="1"/></cellStyleXfs>
+            <cellXfs count="3">
+                <xf numFmtId="0" fontId="0" fillId="0"/>
+                <xf numFmtId="164" fontId="1" fillId="0"/>
+                <xf numFmtId="10" fontId="0" fillId="2"/>
+            </cellXfs>
+        </styleSheet>"#;
+
+        let styles = cell_xf_styles(styles).unwrap();
+
+        assert_eq!(styles.len(), 3);
+        assert_eq!(styles[0], Style::new());
+        assert_eq!(
+            styles[1],
+            Style::new()
+                .font(
+                    Font::new()
+                        .bold(true)
+                        .size(12.0)
+                        .color("#FF0000")
+                        .name("Arial")
+                )
+                .number_format(NumberFormat::currency(2))
+        );
+        assert_eq!(
+            styles[2],
+            Style::new()
+                .fill(Fill::solid("#FFFF00"))
+                .number_format(NumberFormat::percentage(2))
+        );
+    }
+
+    /// TDD RED: Test conditional format fonts and fills are not cell styles
+    #[test]
+    fn test_cell_xf_styles_ignore_dxfs() {
+        // Arrange: A bold cell font followed by a conditional format's dxf
+        let styles = r#"<styleSheet>
+            <fonts count="2">
+                <font><sz val="11"/><name val="Calibri"/></font>
+                <font><b/><sz val="11"/><name val="Calibri"/></font>
+            </fonts>
+            <fills count="2">
+                <fill><patternFill patternType="none"/></fill>
+                <fill><patternFill patternType="gray125"/></fill>
+            </fills>
+            <cellXfs count="2">
+                <xf numFmtId="0" fontId="0" fillId="0"/>
+                <xf numFmtId="0" fontId="1" fillId="0"/>
+            </cellXfs>
+            <dxfs count="1">
+                <dxf>
+                    <font><color rgb="FF9C0006"/></font>
+                    <fill><patternFill patternType="solid"><bgColor rgb="FFFFC7CE"/></patternFill></fill>
+                </dxf>
+            </dxfs>
+        </styleSheet>"#;
+
+        // Act
+        let styles = cell_xf_styles(styles).unwrap();
+
+        // Assert: The bold font keeps its default color
+        assert_eq!(
+            styles[1],
+            Style::new().font(Font::new().bold(true).size(11.0).name("Calibri"))
+        );
+    }
+}
@@ -5,6 +5,7 @@
 
 use super::chart::{Chart, ChartPosition, ChartType};
 use super::line::DataSeries;
+use crate::styles::NumberFormat;
 
 /// Area chart configuration
 ///
@@ -23,6 +24,7 @@ use super::line::DataSeries;
 ///         .categories("Sheet1!$A$2:$A$13"));
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct AreaChart {
     /// Chart title
     title: Option<String>,
@@ -34,10 +36,28 @@ pub struct AreaChart {
     series: Vec<DataSeries>,
     /// Chart position on worksheet
     position: Option<ChartPosition>,
+    /// Background color of the whole chart as hex
+    chart_area_fill: Option<String>,
+    /// Background color of the plot area as hex
+    plot_area_fill: Option<String>,
     /// Show legend
     show_legend: bool,
+    /// Hide the X-axis
+    x_axis_hidden: bool,
+    /// Hide the Y-axis
+    y_axis_hidden: bool,
+    /// Reverse the X-axis direction
+    x_axis_reversed: bool,
+    /// Reverse the Y-axis direction
+    y_axis_reversed: bool,
+    /// Number format for X-axis labels
+    x_axis_number_format: Option<NumberFormat>,
+    /// Number format for Y-axis labels
+    y_axis_number_format: Option<NumberFormat>,
     /// Stacked areas
     stacked: bool,
+    /// Plot each row of a block range as a series instead of each column
+    plot_by_rows: bool,
 }
 
 impl AreaChart {
@@ -50,8 +70,17 @@ impl AreaChart {
             y_axis_title: None,
             series: Vec::new(),
             position: None,
+            chart_area_fill: None,
+            plot_area_fill: None,
             show_legend: true,
+            x_axis_hidden: false,
+            y_axis_hidden: false,
+            x_axis_reversed: false,
+            y_axis_reversed: false,
+            x_axis_number_format: None,
+            y_axis_number_format: None,
             stacked: false,
+            plot_by_rows: false,
         }
     }
 
@@ -90,6 +119,28 @@ impl AreaChart {
         self
     }
 
+    /// Set the background color of the whole chart
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Hex color like "#FFFFFF"
+    #[must_use]
+    pub fn chart_area_fill(mut self, color: impl Into<String>) -> Self {
+        self.chart_area_fill = Some(color.into());
+        self
+    }
+
+    /// Set the background color of the plot area inside the axes
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Hex color like "#F2F2F2"
+    #[must_use]
+    pub fn plot_area_fill(mut self, color: impl Into<String>) -> Self {
+        self.plot_area_fill = Some(color.into());
+        self
+    }
+
     /// Set whether to show legend
     #[must_use]
     pub fn show_legend(mut self, show: bool) -> Self {
@@ -97,6 +148,48 @@ impl AreaChart {
         self
     }
 
+    /// Set whether to hide the X-axis
+    #[must_use]
+    pub fn hide_x_axis(mut self, hide: bool) -> Self {
+        self.x_axis_hidden = hide;
+        self
+    }
+
+    /// Set whether to hide the Y-axis
+    #[must_use]
+    pub fn hide_y_axis(mut self, hide: bool) -> Self {
+        self.y_axis_hidden = hide;
+        self
+    }
+
+    /// Set whether to reverse the X-axis
+    #[must_use]
+    pub fn reverse_x_axis(mut self, reverse: bool) -> Self {
+        self.x_axis_reversed = reverse;
+        self
+    }
+
+    /// Set whether to reverse the Y-axis
+    #[must_use]
+    pub fn reverse_y_axis(mut self, reverse: bool) -> Self {
+        self.y_axis_reversed = reverse;
+        self
+    }
+
+    /// Set the number format for X-axis labels
+    #[must_use]
+    pub fn x_axis_number_format(mut self, format: NumberFormat) -> Self {
+        self.x_axis_number_format = Some(format);
+        self
+    }
+
+    /// Set the number format for Y-axis labels
+    #[must_use]
+    pub fn y_axis_number_format(mut self, format: NumberFormat) -> Self {
+        self.y_axis_number_format = Some(format);
+        self
+    }
+
     /// Set whether areas should be stacked
     #[must_use]
     pub fn stacked(mut self, stacked: bool) -> Self {
@@ -104,6 +197,17 @@ impl AreaChart {
         self
     }
 
+    /// Set whether series are taken from rows rather than columns
+    ///
+    /// A values range spanning several rows and columns is split into one
+    /// series per column by default, or one series per row when enabled.
+    /// Ranges that cover a single row or column are always one series.
+    #[must_use]
+    pub fn plot_by_rows(mut self, enable: bool) -> Self {
+        self.plot_by_rows = enable;
+        self
+    }
+
     /// Get X-axis title
     #[must_use]
     pub fn get_x_axis_title(&self) -> Option<&str> {
@@ -128,11 +232,53 @@ impl AreaChart {
         self.show_legend
     }
 
+    /// Check if the X-axis is hidden
+    #[must_use]
+    pub fn is_x_axis_hidden(&self) -> bool {
+        self.x_axis_hidden
+    }
+
+    /// Check if the Y-axis is hidden
+    #[must_use]
+    pub fn is_y_axis_hidden(&self) -> bool {
+        self.y_axis_hidden
+    }
+
+    /// Check if the X-axis is reversed
+    #[must_use]
+    pub fn is_x_axis_reversed(&self) -> bool {
+        self.x_axis_reversed
+    }
+
+    /// Check if the Y-axis is reversed
+    #[must_use]
+    pub fn is_y_axis_reversed(&self) -> bool {
+        self.y_axis_reversed
+    }
+
+    /// Get the X-axis number format
+    #[must_use]
+    pub fn get_x_axis_number_format(&self) -> Option<&NumberFormat> {
+        self.x_axis_number_format.as_ref()
+    }
+
+    /// Get the Y-axis number format
+    #[must_use]
+    pub fn get_y_axis_number_format(&self) -> Option<&NumberFormat> {
+        self.y_axis_number_format.as_ref()
+    }
+
     /// Check if areas are stacked
     #[must_use]
     pub fn is_stacked(&self) -> bool {
         self.stacked
     }
+
+    /// Check if series are taken from rows
+    #[must_use]
+    pub fn is_plotted_by_rows(&self) -> bool {
+        self.plot_by_rows
+    }
 }
 
 impl Chart for AreaChart {
@@ -147,6 +293,14 @@ impl Chart for AreaChart {
     fn position(&self) -> Option<&ChartPosition> {
         self.position.as_ref()
     }
+
+    fn get_chart_area_fill(&self) -> Option<&str> {
+        self.chart_area_fill.as_deref()
+    }
+
+    fn get_plot_area_fill(&self) -> Option<&str> {
+        self.plot_area_fill.as_deref()
+    }
 }
 
 impl Default for AreaChart {
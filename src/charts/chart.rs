@@ -2,6 +2,8 @@
 //!
 //! Provides common chart functionality and configuration options.
 
+use crate::compat::utils::{get_column_letter, range_boundaries};
+
 /// Chart types available in Excel
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ChartType {
@@ -19,6 +21,30 @@ pub enum ChartType {
     Area,
     /// Doughnut chart
     Doughnut,
+    /// Stock (high-low-close) chart
+    Stock,
+}
+
+/// How a chart plots empty cells in its data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EmptyCellsAs {
+    /// Leave a gap in the line at empty cells
+    #[default]
+    Gaps,
+    /// Plot empty cells as zero
+    Zero,
+    /// Connect the points on either side of empty cells
+    Connect,
+}
+
+impl From<EmptyCellsAs> for rust_xlsxwriter::ChartEmptyCells {
+    fn from(empty_cells: EmptyCellsAs) -> Self {
+        match empty_cells {
+            EmptyCellsAs::Gaps => Self::Gaps,
+            EmptyCellsAs::Zero => Self::Zero,
+            EmptyCellsAs::Connect => Self::Connected,
+        }
+    }
 }
 
 /// Chart positioning on worksheet
@@ -76,6 +102,72 @@ pub trait Chart {
 
     /// Get the chart position
     fn position(&self) -> Option<&ChartPosition>;
+
+    /// Get the background color of the whole chart
+    fn get_chart_area_fill(&self) -> Option<&str>;
+
+    /// Get the background color of the plot area
+    fn get_plot_area_fill(&self) -> Option<&str>;
+}
+
+/// Split a values range spanning several rows and columns into one range
+/// per row or per column
+///
+/// Ranges covering a single row or column, and ranges that can't be
+/// parsed, are returned unchanged.
+pub(crate) fn split_range(range: &str, by_rows: bool) -> Vec<String> {
+    let (sheet, cells) = match range.rfind('!') {
+        Some(bang) => range.split_at(bang + 1),
+        None => ("", range),
+    };
+    let Ok((first_row, first_col, last_row, last_col)) = range_boundaries(cells) else {
+        return vec![range.to_string()];
+    };
+    if first_row == last_row || first_col == last_col {
+        return vec![range.to_string()];
+    }
+
+    let cell = |row: usize, col: usize| format!("${}${row}", get_column_letter(col));
+    if by_rows {
+        (first_row..=last_row)
+            .map(|row| format!("{sheet}{}:{}", cell(row, first_col), cell(row, last_col)))
+            .collect()
+    } else {
+        (first_col..=last_col)
+            .map(|col| format!("{sheet}{}:{}", cell(first_row, col), cell(last_row, col)))
+            .collect()
+    }
+}
+
+/// Join two single-column category ranges into the two-column range of a
+/// multi-level category axis
+///
+/// Returns `None` unless both ranges are on the same sheet and rows, and the
+/// secondary range is the column right of the primary range.
+pub(crate) fn join_category_levels(primary: &str, secondary: &str) -> Option<String> {
+    let split = |range: &str| match range.rfind('!') {
+        Some(bang) => (range[..=bang].to_string(), range[bang + 1..].to_string()),
+        None => (String::new(), range.to_string()),
+    };
+    let (sheet, primary_cells) = split(primary);
+    let (level_sheet, level_cells) = split(secondary);
+    let (top, left, bottom, right) = range_boundaries(&primary_cells).ok()?;
+    let (level_top, level_left, level_bottom, level_right) = range_boundaries(&level_cells).ok()?;
+    if sheet != level_sheet
+        || left != right
+        || level_left != level_right
+        || level_left != left + 1
+        || (top, bottom) != (level_top, level_bottom)
+    {
+        return None;
+    }
+
+    let cell = |row: usize, col: usize| format!("${}${row}", get_column_letter(col));
+    Some(format!(
+        "{sheet}{}:{}",
+        cell(top, left),
+        cell(bottom, level_right)
+    ))
 }
 
 #[cfg(test)]
@@ -113,6 +205,7 @@ mod tests {
             ChartType::Scatter,
             ChartType::Area,
             ChartType::Doughnut,
+            ChartType::Stock,
         ];
 
         for chart_type in chart_types {
@@ -120,4 +213,43 @@ mod tests {
             assert_eq!(chart_type, chart_type);
         }
     }
+
+    /// TDD RED: Test block ranges split by row or column
+    #[test]
+    fn test_split_range() {
+        assert_eq!(
+            split_range("Sheet1!$B$2:$D$3", true),
+            vec!["Sheet1!$B$2:$D$2", "Sheet1!$B$3:$D$3"]
+        );
+        assert_eq!(
+            split_range("'Q1 Sales'!B2:C4", false),
+            vec!["'Q1 Sales'!$B$2:$B$4", "'Q1 Sales'!$C$2:$C$4"]
+        );
+        assert_eq!(
+            split_range("Sheet1!$B$1:$F$1", false),
+            vec!["Sheet1!$B$1:$F$1"]
+        );
+        assert_eq!(
+            split_range("Sheet1!$A$1:$A$9", true),
+            vec!["Sheet1!$A$1:$A$9"]
+        );
+        assert_eq!(split_range("SalesData", true), vec!["SalesData"]);
+    }
+
+    /// TDD RED: Test joining category levels into one range
+    #[test]
+    fn test_join_category_levels() {
+        assert_eq!(
+            join_category_levels("Sheet1!$A$2:$A$7", "Sheet1!B2:B7"),
+            Some("Sheet1!$A$2:$B$7".to_string())
+        );
+        assert_eq!(
+            join_category_levels("'Q1 Sales'!A2:A7", "'Q1 Sales'!B2:B7"),
+            Some("'Q1 Sales'!$A$2:$B$7".to_string())
+        );
+        assert_eq!(join_category_levels("Sheet1!A2:A7", "Sheet1!C2:C7"), None);
+        assert_eq!(join_category_levels("Sheet1!A2:A7", "Sheet1!B2:B8"), None);
+        assert_eq!(join_category_levels("Sheet1!A2:A7", "Sheet2!B2:B7"), None);
+        assert_eq!(join_category_levels("Sheet1!A2:B7", "Sheet1!C2:C7"), None);
+    }
 }
@@ -5,6 +5,7 @@
 
 use super::chart::{Chart, ChartPosition, ChartType};
 use super::line::DataSeries;
+use crate::styles::NumberFormat;
 
 /// Column chart configuration
 ///
@@ -23,6 +24,7 @@ use super::line::DataSeries;
 ///         .categories("Sheet1!$A$2:$A$5"));
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct ColumnChart {
     /// Chart title
     title: Option<String>,
@@ -34,10 +36,34 @@ pub struct ColumnChart {
     series: Vec<DataSeries>,
     /// Chart position on worksheet
     position: Option<ChartPosition>,
+    /// Background color of the whole chart as hex
+    chart_area_fill: Option<String>,
+    /// Background color of the plot area as hex
+    plot_area_fill: Option<String>,
     /// Show legend
     show_legend: bool,
+    /// Hide the X-axis
+    x_axis_hidden: bool,
+    /// Hide the Y-axis
+    y_axis_hidden: bool,
+    /// Reverse the X-axis direction
+    x_axis_reversed: bool,
+    /// Reverse the Y-axis direction
+    y_axis_reversed: bool,
+    /// Treat categories as dates
+    date_axis: bool,
+    /// Number format for X-axis labels
+    x_axis_number_format: Option<NumberFormat>,
+    /// Number format for Y-axis labels
+    y_axis_number_format: Option<NumberFormat>,
     /// Stacked columns
     stacked: bool,
+    /// Plot each row of a block range as a series instead of each column
+    plot_by_rows: bool,
+    /// Show a data table of the series values below the chart
+    data_table: bool,
+    /// Show legend keys beside the data table's series names
+    data_table_legend_keys: bool,
 }
 
 impl ColumnChart {
@@ -50,8 +76,20 @@ impl ColumnChart {
             y_axis_title: None,
             series: Vec::new(),
             position: None,
+            chart_area_fill: None,
+            plot_area_fill: None,
             show_legend: true,
+            x_axis_hidden: false,
+            y_axis_hidden: false,
+            x_axis_reversed: false,
+            y_axis_reversed: false,
+            date_axis: false,
+            x_axis_number_format: None,
+            y_axis_number_format: None,
             stacked: false,
+            plot_by_rows: false,
+            data_table: false,
+            data_table_legend_keys: false,
         }
     }
 
@@ -90,6 +128,28 @@ impl ColumnChart {
         self
     }
 
+    /// Set the background color of the whole chart
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Hex color like "#FFFFFF"
+    #[must_use]
+    pub fn chart_area_fill(mut self, color: impl Into<String>) -> Self {
+        self.chart_area_fill = Some(color.into());
+        self
+    }
+
+    /// Set the background color of the plot area inside the axes
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Hex color like "#F2F2F2"
+    #[must_use]
+    pub fn plot_area_fill(mut self, color: impl Into<String>) -> Self {
+        self.plot_area_fill = Some(color.into());
+        self
+    }
+
     /// Set whether to show legend
     #[must_use]
     pub fn show_legend(mut self, show: bool) -> Self {
@@ -97,6 +157,58 @@ impl ColumnChart {
         self
     }
 
+    /// Set whether to hide the X-axis
+    #[must_use]
+    pub fn hide_x_axis(mut self, hide: bool) -> Self {
+        self.x_axis_hidden = hide;
+        self
+    }
+
+    /// Set whether to hide the Y-axis
+    #[must_use]
+    pub fn hide_y_axis(mut self, hide: bool) -> Self {
+        self.y_axis_hidden = hide;
+        self
+    }
+
+    /// Set whether to reverse the X-axis
+    #[must_use]
+    pub fn reverse_x_axis(mut self, reverse: bool) -> Self {
+        self.x_axis_reversed = reverse;
+        self
+    }
+
+    /// Set whether to reverse the Y-axis
+    #[must_use]
+    pub fn reverse_y_axis(mut self, reverse: bool) -> Self {
+        self.y_axis_reversed = reverse;
+        self
+    }
+
+    /// Set whether the X-axis is a date axis
+    ///
+    /// A date axis spaces date categories evenly by time rather than by
+    /// position.
+    #[must_use]
+    pub fn date_axis(mut self, enable: bool) -> Self {
+        self.date_axis = enable;
+        self
+    }
+
+    /// Set the number format for X-axis labels
+    #[must_use]
+    pub fn x_axis_number_format(mut self, format: NumberFormat) -> Self {
+        self.x_axis_number_format = Some(format);
+        self
+    }
+
+    /// Set the number format for Y-axis labels
+    #[must_use]
+    pub fn y_axis_number_format(mut self, format: NumberFormat) -> Self {
+        self.y_axis_number_format = Some(format);
+        self
+    }
+
     /// Set whether columns should be stacked
     #[must_use]
     pub fn stacked(mut self, stacked: bool) -> Self {
@@ -104,6 +216,33 @@ impl ColumnChart {
         self
     }
 
+    /// Set whether series are taken from rows rather than columns
+    ///
+    /// A values range spanning several rows and columns is split into one
+    /// series per column by default, or one series per row when enabled.
+    /// Ranges that cover a single row or column are always one series.
+    #[must_use]
+    pub fn plot_by_rows(mut self, enable: bool) -> Self {
+        self.plot_by_rows = enable;
+        self
+    }
+
+    /// Set whether to show a data table below the chart
+    ///
+    /// The table lists each series' values under the matching category.
+    #[must_use]
+    pub fn show_data_table(mut self, show: bool) -> Self {
+        self.data_table = show;
+        self
+    }
+
+    /// Set whether the data table shows each series' legend key
+    #[must_use]
+    pub fn data_table_legend_keys(mut self, show: bool) -> Self {
+        self.data_table_legend_keys = show;
+        self
+    }
+
     /// Get X-axis title
     #[must_use]
     pub fn get_x_axis_title(&self) -> Option<&str> {
@@ -128,11 +267,71 @@ impl ColumnChart {
         self.show_legend
     }
 
+    /// Check if the X-axis is hidden
+    #[must_use]
+    pub fn is_x_axis_hidden(&self) -> bool {
+        self.x_axis_hidden
+    }
+
+    /// Check if the Y-axis is hidden
+    #[must_use]
+    pub fn is_y_axis_hidden(&self) -> bool {
+        self.y_axis_hidden
+    }
+
+    /// Check if the X-axis is reversed
+    #[must_use]
+    pub fn is_x_axis_reversed(&self) -> bool {
+        self.x_axis_reversed
+    }
+
+    /// Check if the Y-axis is reversed
+    #[must_use]
+    pub fn is_y_axis_reversed(&self) -> bool {
+        self.y_axis_reversed
+    }
+
+    /// Check if the X-axis is a date axis
+    #[must_use]
+    pub fn is_date_axis(&self) -> bool {
+        self.date_axis
+    }
+
+    /// Get the X-axis number format
+    #[must_use]
+    pub fn get_x_axis_number_format(&self) -> Option<&NumberFormat> {
+        self.x_axis_number_format.as_ref()
+    }
+
+    /// Get the Y-axis number format
+    #[must_use]
+    pub fn get_y_axis_number_format(&self) -> Option<&NumberFormat> {
+        self.y_axis_number_format.as_ref()
+    }
+
     /// Check if columns are stacked
     #[must_use]
     pub fn is_stacked(&self) -> bool {
         self.stacked
     }
+
+    /// Check if series are taken from rows
+    #[must_use]
+    pub fn is_plotted_by_rows(&self) -> bool {
+        self.plot_by_rows
+    }
+
+    /// Check if a data table is shown
+    #[must_use]
+    pub fn has_data_table(&self) -> bool {
+        self.data_table
+    }
+
+    /// Check if the data table shows legend keys
+    #[must_use]
+    pub fn has_data_table_legend_keys(&self) -> bool {
+        self.data_table_legend_keys
+    }
 }
 
 impl Chart for ColumnChart {
@@ -147,6 +346,14 @@ impl Chart for ColumnChart {
     fn position(&self) -> Option<&ChartPosition> {
         self.position.as_ref()
     }
+
+    fn get_chart_area_fill(&self) -> Option<&str> {
+        self.chart_area_fill.as_deref()
+    }
+
+    fn get_plot_area_fill(&self) -> Option<&str> {
+        self.plot_area_fill.as_deref()
+    }
 }
 
 impl Default for ColumnChart {
@@ -243,6 +450,44 @@ mod tests {
         assert!(!chart.is_stacked());
     }
 
+    /// TDD RED: Test column chart row orientation
+    #[test]
+    fn test_column_chart_plot_by_rows() {
+        let chart = ColumnChart::new();
+        assert!(!chart.is_plotted_by_rows());
+
+        let chart = ColumnChart::new().plot_by_rows(true);
+        assert!(chart.is_plotted_by_rows());
+    }
+
+    /// TDD RED: Test column chart data table
+    #[test]
+    fn test_column_chart_data_table() {
+        let chart = ColumnChart::new();
+        assert!(!chart.has_data_table());
+        assert!(!chart.has_data_table_legend_keys());
+
+        let chart = ColumnChart::new()
+            .show_data_table(true)
+            .data_table_legend_keys(true);
+        assert!(chart.has_data_table());
+        assert!(chart.has_data_table_legend_keys());
+    }
+
+    /// TDD RED: Test setting chart and plot area fills
+    #[test]
+    fn test_column_chart_area_fills() {
+        let chart = ColumnChart::new();
+        assert_eq!(chart.get_chart_area_fill(), None);
+        assert_eq!(chart.get_plot_area_fill(), None);
+
+        let chart = ColumnChart::new()
+            .chart_area_fill("#1F3864")
+            .plot_area_fill("#F2F2F2");
+        assert_eq!(chart.get_chart_area_fill(), Some("#1F3864"));
+        assert_eq!(chart.get_plot_area_fill(), Some("#F2F2F2"));
+    }
+
     /// TDD RED: Test column chart builder pattern
     #[test]
     fn test_column_chart_builder() {
@@ -29,6 +29,10 @@ pub struct DoughnutChart {
     series: Vec<DataSeries>,
     /// Chart position on worksheet
     position: Option<ChartPosition>,
+    /// Background color of the whole chart as hex
+    chart_area_fill: Option<String>,
+    /// Background color of the plot area as hex
+    plot_area_fill: Option<String>,
     /// Show legend
     show_legend: bool,
 }
@@ -41,6 +45,8 @@ impl DoughnutChart {
             title: None,
             series: Vec::new(),
             position: None,
+            chart_area_fill: None,
+            plot_area_fill: None,
             show_legend: true,
         }
     }
@@ -66,6 +72,28 @@ impl DoughnutChart {
         self
     }
 
+    /// Set the background color of the whole chart
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Hex color like "#FFFFFF"
+    #[must_use]
+    pub fn chart_area_fill(mut self, color: impl Into<String>) -> Self {
+        self.chart_area_fill = Some(color.into());
+        self
+    }
+
+    /// Set the background color of the plot area inside the axes
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Hex color like "#F2F2F2"
+    #[must_use]
+    pub fn plot_area_fill(mut self, color: impl Into<String>) -> Self {
+        self.plot_area_fill = Some(color.into());
+        self
+    }
+
     /// Set whether to show legend
     #[must_use]
     pub fn show_legend(mut self, show: bool) -> Self {
@@ -98,6 +126,14 @@ impl Chart for DoughnutChart {
     fn position(&self) -> Option<&ChartPosition> {
         self.position.as_ref()
     }
+
+    fn get_chart_area_fill(&self) -> Option<&str> {
+        self.chart_area_fill.as_deref()
+    }
+
+    fn get_plot_area_fill(&self) -> Option<&str> {
+        self.plot_area_fill.as_deref()
+    }
 }
 
 impl Default for DoughnutChart {
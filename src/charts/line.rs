@@ -3,7 +3,8 @@
 //! Provides `LineChart` type for creating line charts with data series,
 //! titles, and customization options.
 
-use super::chart::{Chart, ChartPosition, ChartType};
+use super::chart::{Chart, ChartPosition, ChartType, EmptyCellsAs};
+use crate::styles::NumberFormat;
 
 /// Data series for a line chart
 #[derive(Debug, Clone, PartialEq)]
@@ -12,8 +13,12 @@ pub struct DataSeries {
     name: Option<String>,
     /// Categories range (X-axis) in A1 notation
     categories: Option<String>,
+    /// Outer and inner category ranges of a multi-level category axis
+    category_levels: Option<(String, String)>,
     /// Values range (Y-axis) in A1 notation
     values: String,
+    /// Gradient fill as `(hex color, position percent)` stops and an angle
+    gradient_fill: Option<(Vec<(String, u8)>, u16)>,
 }
 
 impl DataSeries {
@@ -33,7 +38,9 @@ impl DataSeries {
         Self {
             name: None,
             categories: None,
+            category_levels: None,
             values: values.into(),
+            gradient_fill: None,
         }
     }
 
@@ -55,6 +62,61 @@ impl DataSeries {
         self
     }
 
+    /// Set two-level categories, shown as a grouped category axis
+    ///
+    /// Used by line, column, bar and area charts, and replaces any range set
+    /// with [`DataSeries::categories`]. The secondary range must be the
+    /// column right of the primary range, spanning the same rows; leave
+    /// primary cells blank below each group's first row.
+    ///
+    /// # Arguments
+    ///
+    /// * `primary` - Outer level range (e.g., quarters in "Sheet1!$A$2:$A$13")
+    /// * `secondary` - Inner level range (e.g., months in "Sheet1!$B$2:$B$13")
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let series = DataSeries::new("Sheet1!$C$2:$C$13")
+    ///     .categories_multilevel("Sheet1!$A$2:$A$13", "Sheet1!$B$2:$B$13");
+    /// ```
+    #[must_use]
+    pub fn categories_multilevel(
+        mut self,
+        primary: impl Into<String>,
+        secondary: impl Into<String>,
+    ) -> Self {
+        self.category_levels = Some((primary.into(), secondary.into()));
+        self
+    }
+
+    /// Fill the series with a linear gradient
+    ///
+    /// Used by column and area charts. Excel needs 2 to 10 stops; inserting
+    /// the chart fails if the count is outside that, or a stop has an
+    /// invalid color or a position past 100.
+    ///
+    /// # Arguments
+    ///
+    /// * `stops` - `(hex color, position)` pairs, positions from 0 to 100
+    /// * `angle` - Gradient direction in degrees (0-359); 90 runs top to bottom
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let series = DataSeries::new("Sheet1!$B$2:$B$10")
+    ///     .gradient_fill(&[("#1F4E79", 0), ("#DEEBF7", 100)], 90);
+    /// ```
+    #[must_use]
+    pub fn gradient_fill(mut self, stops: &[(&str, u8)], angle: u16) -> Self {
+        let stops = stops
+            .iter()
+            .map(|&(color, position)| (color.to_string(), position))
+            .collect();
+        self.gradient_fill = Some((stops, angle % 360));
+        self
+    }
+
     /// Get series name
     #[must_use]
     pub fn get_name(&self) -> Option<&str> {
@@ -67,11 +129,27 @@ impl DataSeries {
         self.categories.as_deref()
     }
 
+    /// Get the primary and secondary ranges of multi-level categories
+    #[must_use]
+    pub fn get_categories_multilevel(&self) -> Option<(&str, &str)> {
+        self.category_levels
+            .as_ref()
+            .map(|(primary, secondary)| (primary.as_str(), secondary.as_str()))
+    }
+
     /// Get values range
     #[must_use]
     pub fn get_values(&self) -> &str {
         &self.values
     }
+
+    /// Get the gradient fill stops and angle
+    #[must_use]
+    pub fn get_gradient_fill(&self) -> Option<(&[(String, u8)], u16)> {
+        self.gradient_fill
+            .as_ref()
+            .map(|(stops, angle)| (stops.as_slice(), *angle))
+    }
 }
 
 /// Line chart configuration
@@ -91,6 +169,7 @@ impl DataSeries {
 ///         .categories("Sheet1!$A$2:$A$10"));
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct LineChart {
     /// Chart title
     title: Option<String>,
@@ -102,8 +181,38 @@ pub struct LineChart {
     series: Vec<DataSeries>,
     /// Chart position on worksheet
     position: Option<ChartPosition>,
+    /// Background color of the whole chart as hex
+    chart_area_fill: Option<String>,
+    /// Background color of the plot area as hex
+    plot_area_fill: Option<String>,
     /// Show legend
     show_legend: bool,
+    /// Hide the X-axis
+    x_axis_hidden: bool,
+    /// Hide the Y-axis
+    y_axis_hidden: bool,
+    /// Reverse the X-axis direction
+    x_axis_reversed: bool,
+    /// Reverse the Y-axis direction
+    y_axis_reversed: bool,
+    /// Treat categories as dates
+    date_axis: bool,
+    /// Number format for X-axis labels
+    x_axis_number_format: Option<NumberFormat>,
+    /// Number format for Y-axis labels
+    y_axis_number_format: Option<NumberFormat>,
+    /// How empty cells in the data are plotted
+    empty_cells: EmptyCellsAs,
+    /// Draw up/down bars between the first and last series
+    up_down_bars: bool,
+    /// Draw high-low lines from the highest to the lowest series
+    high_low_lines: bool,
+    /// Plot each row of a block range as a series instead of each column
+    plot_by_rows: bool,
+    /// Show a data table of the series values below the chart
+    data_table: bool,
+    /// Show legend keys beside the data table's series names
+    data_table_legend_keys: bool,
 }
 
 impl LineChart {
@@ -116,7 +225,22 @@ impl LineChart {
             y_axis_title: None,
             series: Vec::new(),
             position: None,
+            chart_area_fill: None,
+            plot_area_fill: None,
             show_legend: true,
+            x_axis_hidden: false,
+            y_axis_hidden: false,
+            x_axis_reversed: false,
+            y_axis_reversed: false,
+            date_axis: false,
+            x_axis_number_format: None,
+            y_axis_number_format: None,
+            empty_cells: EmptyCellsAs::Gaps,
+            up_down_bars: false,
+            high_low_lines: false,
+            plot_by_rows: false,
+            data_table: false,
+            data_table_legend_keys: false,
         }
     }
 
@@ -155,6 +279,28 @@ impl LineChart {
         self
     }
 
+    /// Set the background color of the whole chart
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Hex color like "#FFFFFF"
+    #[must_use]
+    pub fn chart_area_fill(mut self, color: impl Into<String>) -> Self {
+        self.chart_area_fill = Some(color.into());
+        self
+    }
+
+    /// Set the background color of the plot area inside the axes
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Hex color like "#F2F2F2"
+    #[must_use]
+    pub fn plot_area_fill(mut self, color: impl Into<String>) -> Self {
+        self.plot_area_fill = Some(color.into());
+        self
+    }
+
     /// Set whether to show legend
     #[must_use]
     pub fn show_legend(mut self, show: bool) -> Self {
@@ -162,6 +308,114 @@ impl LineChart {
         self
     }
 
+    /// Set whether to hide the X-axis
+    #[must_use]
+    pub fn hide_x_axis(mut self, hide: bool) -> Self {
+        self.x_axis_hidden = hide;
+        self
+    }
+
+    /// Set whether to hide the Y-axis
+    #[must_use]
+    pub fn hide_y_axis(mut self, hide: bool) -> Self {
+        self.y_axis_hidden = hide;
+        self
+    }
+
+    /// Set whether to reverse the X-axis
+    #[must_use]
+    pub fn reverse_x_axis(mut self, reverse: bool) -> Self {
+        self.x_axis_reversed = reverse;
+        self
+    }
+
+    /// Set whether to reverse the Y-axis
+    #[must_use]
+    pub fn reverse_y_axis(mut self, reverse: bool) -> Self {
+        self.y_axis_reversed = reverse;
+        self
+    }
+
+    /// Set whether the X-axis is a date axis
+    ///
+    /// A date axis spaces date categories evenly by time rather than by
+    /// position.
+    #[must_use]
+    pub fn date_axis(mut self, enable: bool) -> Self {
+        self.date_axis = enable;
+        self
+    }
+
+    /// Set the number format for X-axis labels
+    #[must_use]
+    pub fn x_axis_number_format(mut self, format: NumberFormat) -> Self {
+        self.x_axis_number_format = Some(format);
+        self
+    }
+
+    /// Set the number format for Y-axis labels
+    #[must_use]
+    pub fn y_axis_number_format(mut self, format: NumberFormat) -> Self {
+        self.y_axis_number_format = Some(format);
+        self
+    }
+
+    /// Set how empty cells in the data are plotted
+    ///
+    /// Defaults to [`EmptyCellsAs::Gaps`], which breaks the line at blanks.
+    #[must_use]
+    pub fn empty_cells(mut self, empty_cells: EmptyCellsAs) -> Self {
+        self.empty_cells = empty_cells;
+        self
+    }
+
+    /// Set whether to draw up/down bars
+    ///
+    /// At each category a bar spans from the first series to the last, as
+    /// in an open-close stock chart.
+    #[must_use]
+    pub fn up_down_bars(mut self, enable: bool) -> Self {
+        self.up_down_bars = enable;
+        self
+    }
+
+    /// Set whether to draw high-low lines
+    ///
+    /// At each category a line spans from the highest to the lowest value
+    /// across all series.
+    #[must_use]
+    pub fn high_low_lines(mut self, enable: bool) -> Self {
+        self.high_low_lines = enable;
+        self
+    }
+
+    /// Set whether series are taken from rows rather than columns
+    ///
+    /// A values range spanning several rows and columns is split into one
+    /// series per column by default, or one series per row when enabled.
+    /// Ranges that cover a single row or column are always one series.
+    #[must_use]
+    pub fn plot_by_rows(mut self, enable: bool) -> Self {
+        self.plot_by_rows = enable;
+        self
+    }
+
+    /// Set whether to show a data table below the chart
+    ///
+    /// The table lists each series' values under the matching category.
+    #[must_use]
+    pub fn show_data_table(mut self, show: bool) -> Self {
+        self.data_table = show;
+        self
+    }
+
+    /// Set whether the data table shows each series' legend key
+    #[must_use]
+    pub fn data_table_legend_keys(mut self, show: bool) -> Self {
+        self.data_table_legend_keys = show;
+        self
+    }
+
     /// Get X-axis title
     #[must_use]
     pub fn get_x_axis_title(&self) -> Option<&str> {
@@ -185,6 +439,84 @@ impl LineChart {
     pub fn is_legend_shown(&self) -> bool {
         self.show_legend
     }
+
+    /// Check if the X-axis is hidden
+    #[must_use]
+    pub fn is_x_axis_hidden(&self) -> bool {
+        self.x_axis_hidden
+    }
+
+    /// Check if the Y-axis is hidden
+    #[must_use]
+    pub fn is_y_axis_hidden(&self) -> bool {
+        self.y_axis_hidden
+    }
+
+    /// Check if the X-axis is reversed
+    #[must_use]
+    pub fn is_x_axis_reversed(&self) -> bool {
+        self.x_axis_reversed
+    }
+
+    /// Check if the Y-axis is reversed
+    #[must_use]
+    pub fn is_y_axis_reversed(&self) -> bool {
+        self.y_axis_reversed
+    }
+
+    /// Check if the X-axis is a date axis
+    #[must_use]
+    pub fn is_date_axis(&self) -> bool {
+        self.date_axis
+    }
+
+    /// Get the X-axis number format
+    #[must_use]
+    pub fn get_x_axis_number_format(&self) -> Option<&NumberFormat> {
+        self.x_axis_number_format.as_ref()
+    }
+
+    /// Get the Y-axis number format
+    #[must_use]
+    pub fn get_y_axis_number_format(&self) -> Option<&NumberFormat> {
+        self.y_axis_number_format.as_ref()
+    }
+
+    /// Get how empty cells are plotted
+    #[must_use]
+    pub fn get_empty_cells(&self) -> EmptyCellsAs {
+        self.empty_cells
+    }
+
+    /// Check if up/down bars are drawn
+    #[must_use]
+    pub fn has_up_down_bars(&self) -> bool {
+        self.up_down_bars
+    }
+
+    /// Check if high-low lines are drawn
+    #[must_use]
+    pub fn has_high_low_lines(&self) -> bool {
+        self.high_low_lines
+    }
+
+    /// Check if series are taken from rows
+    #[must_use]
+    pub fn is_plotted_by_rows(&self) -> bool {
+        self.plot_by_rows
+    }
+
+    /// Check if a data table is shown
+    #[must_use]
+    pub fn has_data_table(&self) -> bool {
+        self.data_table
+    }
+
+    /// Check if the data table shows legend keys
+    #[must_use]
+    pub fn has_data_table_legend_keys(&self) -> bool {
+        self.data_table_legend_keys
+    }
 }
 
 impl Chart for LineChart {
@@ -199,6 +531,14 @@ impl Chart for LineChart {
     fn position(&self) -> Option<&ChartPosition> {
         self.position.as_ref()
     }
+
+    fn get_chart_area_fill(&self) -> Option<&str> {
+        self.chart_area_fill.as_deref()
+    }
+
+    fn get_plot_area_fill(&self) -> Option<&str> {
+        self.plot_area_fill.as_deref()
+    }
 }
 
 impl Default for LineChart {
@@ -234,6 +574,37 @@ mod tests {
         assert_eq!(series.get_categories(), Some("Sheet1!$A$2:$A$10"));
     }
 
+    /// TDD RED: Test data series with two-level categories
+    #[test]
+    fn test_data_series_categories_multilevel() {
+        let series = DataSeries::new("Sheet1!$C$2:$C$13");
+        assert_eq!(series.get_categories_multilevel(), None);
+
+        let series = series.categories_multilevel("Sheet1!$A$2:$A$13", "Sheet1!$B$2:$B$13");
+        assert_eq!(
+            series.get_categories_multilevel(),
+            Some(("Sheet1!$A$2:$A$13", "Sheet1!$B$2:$B$13"))
+        );
+    }
+
+    /// TDD RED: Test data series with a gradient fill
+    #[test]
+    fn test_data_series_gradient_fill() {
+        assert_eq!(
+            DataSeries::new("Sheet1!$B$2:$B$10").get_gradient_fill(),
+            None
+        );
+
+        let series = DataSeries::new("Sheet1!$B$2:$B$10")
+            .gradient_fill(&[("#1F4E79", 0), ("#DEEBF7", 100)], 450);
+        let (stops, angle) = series.get_gradient_fill().unwrap();
+        assert_eq!(
+            stops,
+            &[("#1F4E79".to_string(), 0), ("#DEEBF7".to_string(), 100)]
+        );
+        assert_eq!(angle, 90);
+    }
+
     /// TDD RED: Test data series builder pattern
     #[test]
     fn test_data_series_builder() {
@@ -321,6 +692,61 @@ mod tests {
         assert!(chart.is_legend_shown());
     }
 
+    /// TDD RED: Test hiding line chart axes
+    #[test]
+    fn test_line_chart_hide_axes() {
+        let chart = LineChart::new();
+        assert!(!chart.is_x_axis_hidden());
+        assert!(!chart.is_y_axis_hidden());
+
+        let chart = LineChart::new().hide_x_axis(true).hide_y_axis(true);
+        assert!(chart.is_x_axis_hidden());
+        assert!(chart.is_y_axis_hidden());
+    }
+
+    /// TDD RED: Test line chart up/down bars and high-low lines
+    #[test]
+    fn test_line_chart_stock_lines() {
+        let chart = LineChart::new();
+        assert!(!chart.has_up_down_bars());
+        assert!(!chart.has_high_low_lines());
+
+        let chart = LineChart::new().up_down_bars(true).high_low_lines(true);
+        assert!(chart.has_up_down_bars());
+        assert!(chart.has_high_low_lines());
+    }
+
+    /// TDD RED: Test line chart date axis
+    #[test]
+    fn test_line_chart_date_axis() {
+        let chart = LineChart::new();
+        assert!(!chart.is_date_axis());
+        assert!(chart.get_x_axis_number_format().is_none());
+
+        let chart = LineChart::new()
+            .date_axis(true)
+            .x_axis_number_format(NumberFormat::custom("mmm yy"))
+            .y_axis_number_format(NumberFormat::currency(0));
+        assert!(chart.is_date_axis());
+        assert_eq!(
+            chart.get_x_axis_number_format(),
+            Some(&NumberFormat::custom("mmm yy"))
+        );
+        assert_eq!(
+            chart.get_y_axis_number_format(),
+            Some(&NumberFormat::currency(0))
+        );
+    }
+
+    /// TDD RED: Test line chart empty cells policy
+    #[test]
+    fn test_line_chart_empty_cells() {
+        assert_eq!(LineChart::new().get_empty_cells(), EmptyCellsAs::Gaps);
+
+        let chart = LineChart::new().empty_cells(EmptyCellsAs::Connect);
+        assert_eq!(chart.get_empty_cells(), EmptyCellsAs::Connect);
+    }
+
     /// TDD RED: Test line chart builder pattern
     #[test]
     fn test_line_chart_builder() {
@@ -1,7 +1,7 @@
 //! Excel chart module
 //!
 //! Provides types for creating charts in Excel worksheets including line,
-//! column, bar, pie, scatter, area, and doughnut charts.
+//! column, bar, pie, scatter, area, doughnut, and stock charts.
 
 pub mod area;
 pub mod bar;
@@ -11,13 +11,15 @@ pub mod doughnut;
 pub mod line;
 pub mod pie;
 pub mod scatter;
+pub mod stock;
 
 // Re-export for convenience
 pub use area::AreaChart;
 pub use bar::BarChart;
-pub use chart::{Chart, ChartPosition, ChartType};
+pub use chart::{Chart, ChartPosition, ChartType, EmptyCellsAs};
 pub use column::ColumnChart;
 pub use doughnut::DoughnutChart;
 pub use line::{DataSeries, LineChart};
 pub use pie::PieChart;
 pub use scatter::ScatterChart;
+pub use stock::StockChart;
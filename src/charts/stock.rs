@@ -0,0 +1,266 @@
+//! Stock chart implementation
+//!
+//! Provides `StockChart` type for creating high-low-close and
+//! open-high-low-close stock charts from price ranges.
+
+use super::chart::{Chart, ChartPosition, ChartType};
+
+/// Stock chart configuration
+///
+/// Unlike the other charts a stock chart has a fixed set of price series
+/// rather than a list of `DataSeries`. Each category gets a high-low line;
+/// when an open range is set, up/down bars span the open and close prices.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use xlsxpress::charts::StockChart;
+///
+/// let chart = StockChart::new("Sheet1!$C$2:$C$11", "Sheet1!$D$2:$D$11", "Sheet1!$E$2:$E$11")
+///     .open("Sheet1!$B$2:$B$11")
+///     .categories("Sheet1!$A$2:$A$11")
+///     .title("ACME Corp");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StockChart {
+    /// Chart title
+    title: Option<String>,
+    /// X-axis title
+    x_axis_title: Option<String>,
+    /// Y-axis title
+    y_axis_title: Option<String>,
+    /// Categories range, usually dates
+    categories: Option<String>,
+    /// Opening prices range
+    open: Option<String>,
+    /// High prices range
+    high: String,
+    /// Low prices range
+    low: String,
+    /// Closing prices range
+    close: String,
+    /// Chart position on worksheet
+    position: Option<ChartPosition>,
+    /// Background color of the whole chart as hex
+    chart_area_fill: Option<String>,
+    /// Background color of the plot area as hex
+    plot_area_fill: Option<String>,
+    /// Show legend
+    show_legend: bool,
+}
+
+impl StockChart {
+    /// Create a new high-low-close stock chart
+    ///
+    /// # Arguments
+    ///
+    /// * `high` - High prices range (e.g., "Sheet1!$C$2:$C$11")
+    /// * `low` - Low prices range
+    /// * `close` - Closing prices range
+    #[must_use]
+    pub fn new(high: impl Into<String>, low: impl Into<String>, close: impl Into<String>) -> Self {
+        Self {
+            title: None,
+            x_axis_title: None,
+            y_axis_title: None,
+            categories: None,
+            open: None,
+            high: high.into(),
+            low: low.into(),
+            close: close.into(),
+            position: None,
+            chart_area_fill: None,
+            plot_area_fill: None,
+            show_legend: true,
+        }
+    }
+
+    /// Set chart title
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set X-axis title
+    #[must_use]
+    pub fn x_axis_title(mut self, title: impl Into<String>) -> Self {
+        self.x_axis_title = Some(title.into());
+        self
+    }
+
+    /// Set Y-axis title
+    #[must_use]
+    pub fn y_axis_title(mut self, title: impl Into<String>) -> Self {
+        self.y_axis_title = Some(title.into());
+        self
+    }
+
+    /// Set categories range (X-axis), usually the trading dates
+    #[must_use]
+    pub fn categories(mut self, categories: impl Into<String>) -> Self {
+        self.categories = Some(categories.into());
+        self
+    }
+
+    /// Set opening prices range, making this an open-high-low-close chart
+    #[must_use]
+    pub fn open(mut self, open: impl Into<String>) -> Self {
+        self.open = Some(open.into());
+        self
+    }
+
+    /// Set chart position on worksheet
+    #[must_use]
+    pub fn position(mut self, position: ChartPosition) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    /// Set the background color of the whole chart
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Hex color like "#FFFFFF"
+    #[must_use]
+    pub fn chart_area_fill(mut self, color: impl Into<String>) -> Self {
+        self.chart_area_fill = Some(color.into());
+        self
+    }
+
+    /// Set the background color of the plot area inside the axes
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Hex color like "#F2F2F2"
+    #[must_use]
+    pub fn plot_area_fill(mut self, color: impl Into<String>) -> Self {
+        self.plot_area_fill = Some(color.into());
+        self
+    }
+
+    /// Set whether to show legend
+    #[must_use]
+    pub fn show_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    /// Get X-axis title
+    #[must_use]
+    pub fn get_x_axis_title(&self) -> Option<&str> {
+        self.x_axis_title.as_deref()
+    }
+
+    /// Get Y-axis title
+    #[must_use]
+    pub fn get_y_axis_title(&self) -> Option<&str> {
+        self.y_axis_title.as_deref()
+    }
+
+    /// Get categories range
+    #[must_use]
+    pub fn get_categories(&self) -> Option<&str> {
+        self.categories.as_deref()
+    }
+
+    /// Get opening prices range
+    #[must_use]
+    pub fn get_open(&self) -> Option<&str> {
+        self.open.as_deref()
+    }
+
+    /// Get high prices range
+    #[must_use]
+    pub fn get_high(&self) -> &str {
+        &self.high
+    }
+
+    /// Get low prices range
+    #[must_use]
+    pub fn get_low(&self) -> &str {
+        &self.low
+    }
+
+    /// Get closing prices range
+    #[must_use]
+    pub fn get_close(&self) -> &str {
+        &self.close
+    }
+
+    /// Check if legend is shown
+    #[must_use]
+    pub fn is_legend_shown(&self) -> bool {
+        self.show_legend
+    }
+}
+
+impl Chart for StockChart {
+    fn chart_type(&self) -> ChartType {
+        ChartType::Stock
+    }
+
+    fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    fn position(&self) -> Option<&ChartPosition> {
+        self.position.as_ref()
+    }
+
+    fn get_chart_area_fill(&self) -> Option<&str> {
+        self.chart_area_fill.as_deref()
+    }
+
+    fn get_plot_area_fill(&self) -> Option<&str> {
+        self.plot_area_fill.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test stock chart creation
+    #[test]
+    fn test_stock_chart_new() {
+        let chart = StockChart::new("Sheet1!$C$2:$C$6", "Sheet1!$D$2:$D$6", "Sheet1!$E$2:$E$6");
+        assert!(Chart::title(&chart).is_none());
+        assert_eq!(chart.get_high(), "Sheet1!$C$2:$C$6");
+        assert_eq!(chart.get_low(), "Sheet1!$D$2:$D$6");
+        assert_eq!(chart.get_close(), "Sheet1!$E$2:$E$6");
+        assert!(chart.get_open().is_none());
+        assert!(chart.get_categories().is_none());
+        assert!(chart.is_legend_shown());
+    }
+
+    /// TDD RED: Test stock chart builder pattern
+    #[test]
+    fn test_stock_chart_builder() {
+        let chart = StockChart::new("Sheet1!$C$2:$C$6", "Sheet1!$D$2:$D$6", "Sheet1!$E$2:$E$6")
+            .open("Sheet1!$B$2:$B$6")
+            .categories("Sheet1!$A$2:$A$6")
+            .title("ACME Corp")
+            .x_axis_title("Date")
+            .y_axis_title("Price")
+            .show_legend(false);
+
+        assert_eq!(Chart::title(&chart), Some("ACME Corp"));
+        assert_eq!(chart.get_open(), Some("Sheet1!$B$2:$B$6"));
+        assert_eq!(chart.get_categories(), Some("Sheet1!$A$2:$A$6"));
+        assert_eq!(chart.get_x_axis_title(), Some("Date"));
+        assert_eq!(chart.get_y_axis_title(), Some("Price"));
+        assert!(!chart.is_legend_shown());
+    }
+
+    /// TDD RED: Test chart trait implementation
+    #[test]
+    fn test_stock_chart_trait() {
+        let pos = ChartPosition::new(1, 6);
+        let chart = StockChart::new("Sheet1!$C$2:$C$6", "Sheet1!$D$2:$D$6", "Sheet1!$E$2:$E$6")
+            .position(pos.clone());
+
+        assert_eq!(chart.chart_type(), ChartType::Stock);
+        assert_eq!(Chart::position(&chart), Some(&pos));
+    }
+}
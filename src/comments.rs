@@ -0,0 +1,468 @@
+//! Cell comments
+//!
+//! Reads a worksheet's legacy notes and threaded comments from the xlsx
+//! package, and writes threaded comments.
+//!
+//! `rust_xlsxwriter` only writes legacy notes, so threaded comments are
+//! collected here and added to the saved package afterwards: a
+//! `threadedComments` part per worksheet, a shared `persons` part, and the
+//! relationships and content types that link them. Each thread also gets a
+//! legacy note so older Excel versions can still show it.
+
+use crate::compat::utils::{coordinate_from_string, coordinate_to_string};
+use crate::error::Result;
+use crate::package::{rewrite_package, text_elements, Package};
+use crate::reader::CellComment;
+use chrono::Timelike;
+use quick_xml::escape::escape;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::io::Cursor;
+
+/// Relationship type of a worksheet's threaded comments part
+const THREADED_COMMENT_RELATIONSHIP: &str =
+    "http://schemas.microsoft.com/office/2017/10/relationships/threadedComment";
+
+/// Relationship type of the workbook's persons part
+const PERSON_RELATIONSHIP: &str =
+    "http://schemas.microsoft.com/office/2017/10/relationships/person";
+
+/// Namespaces of the threaded comments and persons parts
+const NAMESPACES: &str =
+    "xmlns=\"http://schemas.microsoft.com/office/spreadsheetml/2018/threadedcomments\" \
+     xmlns:x=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\"";
+
+/// Part name of the shared persons list
+const PERSONS_PART: &str = "xl/persons/person.xml";
+
+/// Part name of the package content types
+const CONTENT_TYPES_PART: &str = "[Content_Types].xml";
+
+/// Part name of the workbook relationships
+const WORKBOOK_RELS_PART: &str = "xl/_rels/workbook.xml.rels";
+
+/// Relationships part with no relationships yet
+const EMPTY_RELATIONSHIPS: &str =
+    "<Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\"></Relationships>";
+
+/// XML declaration written at the top of each new part
+const XML_DECLARATION: &str = "<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\"?>\r\n";
+
+/// Fallback text Excel writes into the legacy note of a threaded comment
+const LEGACY_NOTE_PREAMBLE: &str = "[Threaded comment]\n\nYour version of Excel allows you to \
+     read this threaded comment; however, any edits to it will get removed if the file is \
+     opened in a newer version of Excel. Learn more: \
+     https://go.microsoft.com/fwlink/?linkid=870924\n\nComment:\n    ";
+
+/// Prefix of the legacy note author Excel links to a threaded comment
+const THREADED_NOTE_AUTHOR_PREFIX: &str = "tc=";
+
+/// One comment in a thread
+#[derive(Debug, Clone)]
+struct Comment {
+    /// Workbook-wide comment number, used for the comment's ID
+    id: usize,
+    /// Index into the persons list
+    person: usize,
+    /// Comment text
+    text: String,
+    /// Creation time, e.g. `2024-05-01T09:30:00.00`
+    created: String,
+}
+
+/// Comment thread anchored to one cell
+#[derive(Debug, Clone, Default)]
+struct Thread {
+    /// First comment followed by replies
+    comments: Vec<Comment>,
+    /// Whether the thread has been resolved
+    resolved: bool,
+}
+
+/// Threaded comments of a workbook, added to the package when it is saved
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ThreadedComments {
+    /// Author display names; the index identifies the person
+    persons: Vec<String>,
+    /// Threads per zero-based sheet index, keyed by (row, col)
+    sheets: BTreeMap<usize, BTreeMap<(u32, u16), Thread>>,
+    /// Comments created so far, used to number comment IDs
+    count: usize,
+}
+
+impl ThreadedComments {
+    /// Check if there are no threaded comments
+    pub(crate) fn is_empty(&self) -> bool {
+        self.sheets.is_empty()
+    }
+
+    /// Add a comment, starting a thread or replying to the cell's thread
+    ///
+    /// Returns the legacy note text for the cell's whole thread.
+    pub(crate) fn add(
+        &mut self,
+        sheet: usize,
+        row: u32,
+        col: u16,
+        author: &str,
+        text: &str,
+    ) -> String {
+        let person = self.person(author);
+        let now = chrono::Local::now().naive_local();
+        let created = format!(
+            "{}.{:02}",
+            now.format("%Y-%m-%dT%H:%M:%S"),
+            now.nanosecond() / 10_000_000
+        );
+        let thread = self
+            .sheets
+            .entry(sheet)
+            .or_default()
+            .entry((row, col))
+            .or_default();
+        thread.comments.push(Comment {
+            id: self.count,
+            person,
+            text: text.to_string(),
+            created,
+        });
+        self.count += 1;
+        Self::legacy_note_text(thread)
+    }
+
+    /// Mark the thread on a cell as resolved
+    ///
+    /// Returns `false` if the cell has no thread.
+    pub(crate) fn resolve(&mut self, sheet: usize, row: u32, col: u16) -> bool {
+        self.sheets
+            .get_mut(&sheet)
+            .and_then(|threads| threads.get_mut(&(row, col)))
+            .map(|thread| thread.resolved = true)
+            .is_some()
+    }
+
+    /// Legacy note author that links a note to its thread
+    pub(crate) fn legacy_note_author(&self, sheet: usize, row: u32, col: u16) -> Option<String> {
+        let first = self
+            .sheets
+            .get(&sheet)?
+            .get(&(row, col))?
+            .comments
+            .first()?;
+        Some(format!(
+            "{THREADED_NOTE_AUTHOR_PREFIX}{}",
+            comment_id(first.id)
+        ))
+    }
+
+    /// Index of a person, adding them if new
+    fn person(&mut self, author: &str) -> usize {
+        if let Some(index) = self.persons.iter().position(|name| name == author) {
+            return index;
+        }
+        self.persons.push(author.to_string());
+        self.persons.len() - 1
+    }
+
+    /// Fallback text for older Excel versions: the comment and its replies
+    fn legacy_note_text(thread: &Thread) -> String {
+        let mut text = String::from(LEGACY_NOTE_PREAMBLE);
+        for (index, comment) in thread.comments.iter().enumerate() {
+            if index > 0 {
+                text.push_str("\nReply:\n    ");
+            }
+            text.push_str(&comment.text);
+        }
+        text
+    }
+
+    /// Add the threaded comment parts to a saved xlsx package
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidFormat` if the package can't be rewritten.
+    pub(crate) fn add_to_package(&self, package: &[u8]) -> Result<Vec<u8>> {
+        let mut saved = Package::open(Box::new(Cursor::new(package.to_vec())))?;
+        let comment_parts = self.comment_parts();
+        let mut parts = BTreeMap::new();
+
+        let overrides: String = comment_parts
+            .iter()
+            .map(|(_, part)| {
+                content_type_override(part, "application/vnd.ms-excel.threadedcomments+xml")
+            })
+            .chain(std::iter::once(content_type_override(
+                PERSONS_PART,
+                "application/vnd.ms-excel.person+xml",
+            )))
+            .collect();
+        let content_types = saved.require_text(CONTENT_TYPES_PART)?;
+        parts.insert(
+            CONTENT_TYPES_PART.to_string(),
+            insert_before(&content_types, "</Types>", &overrides),
+        );
+
+        let workbook_rels = saved.require_text(WORKBOOK_RELS_PART)?;
+        parts.insert(
+            WORKBOOK_RELS_PART.to_string(),
+            add_relationship(&workbook_rels, PERSON_RELATIONSHIP, "persons/person.xml"),
+        );
+
+        for (sheet, part) in &comment_parts {
+            let rels_part = format!("xl/worksheets/_rels/sheet{}.xml.rels", sheet + 1);
+            let rels = saved
+                .read_text(&rels_part)?
+                .unwrap_or_else(|| format!("{XML_DECLARATION}{EMPTY_RELATIONSHIPS}"));
+            let target = format!("../{}", part.trim_start_matches("xl/"));
+            parts.insert(
+                rels_part,
+                add_relationship(&rels, THREADED_COMMENT_RELATIONSHIP, &target),
+            );
+            parts.insert(part.clone(), self.threaded_comments_xml(*sheet));
+        }
+        parts.insert(PERSONS_PART.to_string(), self.persons_xml());
+
+        rewrite_package(package, &parts)
+    }
+
+    /// Threaded comments part name for each sheet with comments
+    fn comment_parts(&self) -> Vec<(usize, String)> {
+        self.sheets
+            .keys()
+            .enumerate()
+            .map(|(n, &sheet)| {
+                (
+                    sheet,
+                    format!("xl/threadedComments/threadedComment{}.xml", n + 1),
+                )
+            })
+            .collect()
+    }
+
+    /// XML of the threaded comments part for one sheet
+    fn threaded_comments_xml(&self, sheet: usize) -> String {
+        let mut xml = format!("{XML_DECLARATION}<ThreadedComments {NAMESPACES}>");
+        for (&(row, col), thread) in self.sheets.get(&sheet).into_iter().flatten() {
+            let reference = coordinate_to_string(row as usize + 1, usize::from(col) + 1);
+            let parent = thread.comments.first().map(|first| comment_id(first.id));
+            for (index, comment) in thread.comments.iter().enumerate() {
+                let mut attributes = format!(
+                    "ref=\"{reference}\" dT=\"{}\" personId=\"{}\" id=\"{}\"",
+                    comment.created,
+                    person_id(comment.person),
+                    comment_id(comment.id)
+                );
+                if index == 0 && thread.resolved {
+                    attributes.push_str(" done=\"1\"");
+                }
+                if let Some(parent) = parent.as_ref().filter(|_| index > 0) {
+                    let _ = write!(attributes, " parentId=\"{parent}\"");
+                }
+                let _ = write!(
+                    xml,
+                    "<threadedComment {attributes}><text>{}</text></threadedComment>",
+                    escape(comment.text.as_str())
+                );
+            }
+        }
+        xml.push_str("</ThreadedComments>");
+        xml
+    }
+
+    /// XML of the shared persons part
+    fn persons_xml(&self) -> String {
+        let mut xml = format!("{XML_DECLARATION}<personList {NAMESPACES}>");
+        for (index, name) in self.persons.iter().enumerate() {
+            let name = escape(name.as_str());
+            let _ = write!(
+                xml,
+                "<person displayName=\"{name}\" id=\"{}\" userId=\"{name}\" providerId=\"None\"/>",
+                person_id(index)
+            );
+        }
+        xml.push_str("</personList>");
+        xml
+    }
+}
+
+/// Read the notes and threaded comments on a worksheet
+///
+/// Legacy notes that only mirror a threaded comment are left out, so each
+/// comment appears once. Comments are ordered by row, then column, with
+/// replies after the comment they answer.
+///
+/// # Errors
+///
+/// Returns `Error::SheetNotFound` if the sheet doesn't exist.
+/// Returns `Error::InvalidFormat` if the package or its parts are malformed.
+pub(crate) fn read_comments(package: &mut Package, sheet: &str) -> Result<Vec<CellComment>> {
+    let sheet_part = package.sheet_part(sheet)?;
+    let relationships = package.relationships(&sheet_part)?;
+    let target = |suffix: &str| {
+        relationships
+            .iter()
+            .find(|rel| rel.kind.ends_with(suffix))
+            .map(|rel| rel.target.clone())
+    };
+
+    let mut comments = Vec::new();
+    if let Some(part) = target("/comments") {
+        comments.extend(legacy_notes(&package.require_text(&part)?)?);
+    }
+    if let Some(part) = target("/threadedComment") {
+        let persons = read_persons(package)?;
+        comments.extend(threaded_comments(&package.require_text(&part)?, &persons)?);
+    }
+    comments.sort_by_key(|&(row, col, _, _)| (row, col));
+    Ok(comments)
+}
+
+/// Legacy notes from a comments part, skipping threaded comment mirrors
+fn legacy_notes(xml: &str) -> Result<Vec<CellComment>> {
+    let authors: Vec<String> = text_elements(xml, b"author")?
+        .into_iter()
+        .map(|(_, text)| text)
+        .collect();
+    Ok(text_elements(xml, b"comment")?
+        .into_iter()
+        .filter_map(|(attrs, text)| {
+            let author = attrs
+                .get("authorId")
+                .and_then(|id| id.parse::<usize>().ok())
+                .and_then(|id| authors.get(id).cloned());
+            if author
+                .as_deref()
+                .is_some_and(|author| author.starts_with(THREADED_NOTE_AUTHOR_PREFIX))
+            {
+                return None;
+            }
+            let (row, col) = cell_position(&attrs)?;
+            Some((row, col, text, author))
+        })
+        .collect())
+}
+
+/// Threaded comments from a threaded comments part
+fn threaded_comments(xml: &str, persons: &HashMap<String, String>) -> Result<Vec<CellComment>> {
+    Ok(text_elements(xml, b"threadedComment")?
+        .into_iter()
+        .filter_map(|(attrs, text)| {
+            let author = attrs
+                .get("personId")
+                .and_then(|id| persons.get(id).cloned());
+            let (row, col) = cell_position(&attrs)?;
+            Some((row, col, text, author))
+        })
+        .collect())
+}
+
+/// Display names by person ID from the workbook's persons part
+fn read_persons(package: &mut Package) -> Result<HashMap<String, String>> {
+    let Some(part) = package
+        .relationships("xl/workbook.xml")?
+        .into_iter()
+        .find(|rel| rel.kind == PERSON_RELATIONSHIP)
+        .map(|rel| rel.target)
+    else {
+        return Ok(HashMap::new());
+    };
+    Ok(text_elements(&package.require_text(&part)?, b"person")?
+        .into_iter()
+        .filter_map(|(mut attrs, _)| Some((attrs.remove("id")?, attrs.remove("displayName")?)))
+        .collect())
+}
+
+/// Zero-based position from an element's `ref` attribute
+fn cell_position(attrs: &HashMap<String, String>) -> Option<(usize, usize)> {
+    let (row, col) = coordinate_from_string(attrs.get("ref")?).ok()?;
+    Some((row - 1, col - 1))
+}
+
+/// GUID-style ID of the nth comment in the workbook
+fn comment_id(number: usize) -> String {
+    format!("{{00000000-0000-4000-8000-{:012X}}}", number + 1)
+}
+
+/// GUID-style ID of the nth person
+fn person_id(index: usize) -> String {
+    format!("{{{:08X}-0000-4000-8000-000000000000}}", index + 1)
+}
+
+/// Content type `Override` element for a part
+fn content_type_override(part: &str, content_type: &str) -> String {
+    format!("<Override PartName=\"/{part}\" ContentType=\"{content_type}\"/>")
+}
+
+/// Append a relationship with the next free `rIdN` to a `.rels` part
+fn add_relationship(xml: &str, kind: &str, target: &str) -> String {
+    let id = xml.matches("<Relationship ").count() + 1;
+    let relationship =
+        format!("<Relationship Id=\"rId{id}\" Type=\"{kind}\" Target=\"{target}\"/>");
+    insert_before(xml, "</Relationships>", &relationship)
+}
+
+/// Insert `content` before the last occurrence of `closing_tag`
+fn insert_before(xml: &str, closing_tag: &str, content: &str) -> String {
+    match xml.rfind(closing_tag) {
+        Some(position) => format!("{}{content}{}", &xml[..position], &xml[position..]),
+        None => xml.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test replies join the cell's thread and resolving marks it done
+    #[test]
+    fn test_threaded_comments_reply_and_resolve() {
+        let mut comments = ThreadedComments::default();
+        comments.add(0, 0, 0, "Alice", "Check this");
+        let note = comments.add(0, 0, 0, "Bob", "Done <fixed>");
+        assert!(note.ends_with("Check this\nReply:\n    Done <fixed>"));
+        assert!(comments.resolve(0, 0, 0));
+        assert!(!comments.resolve(0, 5, 5));
+
+        let xml = comments.threaded_comments_xml(0);
+        assert_eq!(xml.matches("<threadedComment ").count(), 2);
+        assert!(xml.contains("done=\"1\""));
+        assert!(xml.contains(&format!("parentId=\"{}\"", comment_id(0))));
+        assert!(xml.contains("<text>Done &lt;fixed&gt;</text>"));
+        assert_eq!(
+            comments.legacy_note_author(0, 0, 0),
+            Some(format!("tc={}", comment_id(0)))
+        );
+    }
+
+    /// TDD RED: Test legacy notes resolve authors and skip thread mirrors
+    #[test]
+    fn test_legacy_notes() {
+        let xml = "<comments><authors><author>Alice</author><author>tc={1}</author></authors>\
+            <commentList><comment ref=\"C2\" authorId=\"0\"><text><r><rPr><b/></rPr><t>Alice:</t></r>\
+            <r><t xml:space=\"preserve\"> see &amp; fix</t></r></text></comment>\
+            <comment ref=\"A1\" authorId=\"1\"><text><t>[Threaded comment]</t></text></comment>\
+            </commentList></comments>";
+
+        let notes = legacy_notes(xml).unwrap();
+
+        assert_eq!(
+            notes,
+            vec![(
+                1,
+                2,
+                "Alice: see & fix".to_string(),
+                Some("Alice".to_string())
+            )]
+        );
+    }
+
+    /// TDD RED: Test relationships get the next free ID
+    #[test]
+    fn test_add_relationship() {
+        let rels =
+            "<Relationships><Relationship Id=\"rId1\" Type=\"a\" Target=\"b\"/></Relationships>";
+        let xml = add_relationship(rels, PERSON_RELATIONSHIP, "persons/person.xml");
+        assert!(xml.contains("<Relationship Id=\"rId2\""));
+        assert!(xml.ends_with("</Relationships>"));
+    }
+}
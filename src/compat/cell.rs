@@ -4,6 +4,8 @@
 //! 1-indexed row/column and A1 notation coordinate.
 
 use crate::compat::utils::coordinate_to_string;
+use crate::number_formats::GENERAL;
+use chrono::NaiveDateTime;
 
 /// Cell value types compatible with `OpenPyXL`
 #[derive(Debug, Clone, PartialEq)]
@@ -14,6 +16,14 @@ pub enum CellValue {
     Number(f64),
     /// Boolean value
     Boolean(bool),
+    /// Date value, at midnight
+    Date(NaiveDateTime),
+    /// Date value with a time of day
+    DateTime(NaiveDateTime),
+    /// Time duration, in days
+    Duration(f64),
+    /// Error value, e.g. `#DIV/0!`
+    Error(String),
     /// Empty cell
     Empty,
 }
@@ -42,12 +52,21 @@ impl From<bool> for CellValue {
     }
 }
 
+impl From<NaiveDateTime> for CellValue {
+    fn from(d: NaiveDateTime) -> Self {
+        Self::Date(d)
+    }
+}
+
 impl std::fmt::Display for CellValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::String(s) => write!(f, "{s}"),
             Self::Number(n) => write!(f, "{n}"),
             Self::Boolean(b) => write!(f, "{b}"),
+            Self::Date(d) | Self::DateTime(d) => write!(f, "{d}"),
+            Self::Duration(days) => write!(f, "{days}"),
+            Self::Error(e) => write!(f, "{e}"),
             Self::Empty => write!(f, ""),
         }
     }
@@ -65,6 +84,8 @@ pub struct Cell {
     column: usize,
     /// Cell value
     value: CellValue,
+    /// Number format code
+    number_format: String,
 }
 
 impl Cell {
@@ -81,9 +102,21 @@ impl Cell {
             row,
             column,
             value: value.into(),
+            number_format: GENERAL.to_string(),
         }
     }
 
+    /// Set the cell's number format code
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Excel format code, e.g. `"$#,##0.00"`
+    #[must_use]
+    pub fn with_number_format(mut self, format: impl Into<String>) -> Self {
+        self.number_format = format.into();
+        self
+    }
+
     /// Get the cell's row number (1-indexed)
     #[must_use]
     pub fn row(&self) -> usize {
@@ -108,6 +141,33 @@ impl Cell {
         &self.value
     }
 
+    /// Get the cell's number format code
+    ///
+    /// Matches `OpenPyXL`'s `cell.number_format`; "General" when the cell
+    /// has no explicit format or its format is unknown.
+    #[must_use]
+    pub fn number_format(&self) -> &str {
+        &self.number_format
+    }
+
+    /// Get the cell's data type code
+    ///
+    /// Matches `OpenPyXL`'s `cell.data_type`: `'n'` for numbers and empty
+    /// cells, `'s'` for strings, `'b'` for booleans, `'d'` for dates, times
+    /// and durations, `'e'` for errors and `'f'` for formulas (strings
+    /// starting with `=`).
+    #[must_use]
+    pub fn data_type(&self) -> char {
+        match &self.value {
+            CellValue::String(s) if s.starts_with('=') => 'f',
+            CellValue::String(_) => 's',
+            CellValue::Boolean(_) => 'b',
+            CellValue::Date(_) | CellValue::DateTime(_) | CellValue::Duration(_) => 'd',
+            CellValue::Error(_) => 'e',
+            CellValue::Number(_) | CellValue::Empty => 'n',
+        }
+    }
+
     /// Set the cell's value
     pub fn set_value(&mut self, value: impl Into<CellValue>) {
         self.value = value.into();
@@ -148,6 +208,32 @@ mod tests {
         assert_eq!(cell.value(), &CellValue::Boolean(true));
     }
 
+    /// TDD RED: Test cell number format defaults to General
+    #[test]
+    fn test_cell_number_format() {
+        let cell = Cell::new(1, 1, 9.5);
+        assert_eq!(cell.number_format(), "General");
+
+        let cell = cell.with_number_format("0.00%");
+        assert_eq!(cell.number_format(), "0.00%");
+    }
+
+    /// TDD RED: Test data type codes for each value type
+    #[test]
+    fn test_cell_data_type() {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 3, 15)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+
+        assert_eq!(Cell::new(1, 1, 42.0).data_type(), 'n');
+        assert_eq!(Cell::new(1, 1, CellValue::Empty).data_type(), 'n');
+        assert_eq!(Cell::new(1, 1, "Hello").data_type(), 's');
+        assert_eq!(Cell::new(1, 1, true).data_type(), 'b');
+        assert_eq!(Cell::new(1, 1, date).data_type(), 'd');
+        assert_eq!(Cell::new(1, 1, "=SUM(A1:A3)").data_type(), 'f');
+    }
+
     /// TDD RED: Test cell value modification
     #[test]
     fn test_cell_set_value() {
@@ -180,6 +266,13 @@ mod tests {
         assert_eq!(CellValue::String("test".to_string()).to_string(), "test");
         assert_eq!(CellValue::Number(42.5).to_string(), "42.5");
         assert_eq!(CellValue::Boolean(true).to_string(), "true");
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 3, 15)
+            .unwrap()
+            .and_hms_opt(9, 30, 0)
+            .unwrap();
+        assert_eq!(CellValue::DateTime(date).to_string(), "2024-03-15 09:30:00");
+        assert_eq!(CellValue::Duration(1.5).to_string(), "1.5");
+        assert_eq!(CellValue::Error("#N/A".to_string()).to_string(), "#N/A");
         assert_eq!(CellValue::Empty.to_string(), "");
     }
 }
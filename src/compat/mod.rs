@@ -8,11 +8,14 @@ pub mod cell;
 pub mod utils;
 pub mod workbook;
 pub mod worksheet;
+pub mod writable;
 
 // Re-export for convenience
 pub use cell::{Cell, CellValue};
 pub use utils::{
     column_index_from_string, coordinate_from_string, coordinate_to_string, get_column_letter,
+    range_boundaries,
 };
-pub use workbook::{load_workbook, Workbook};
+pub use workbook::{load_workbook, load_workbook_with_options, LoadOptions, Workbook};
 pub use worksheet::{RowIterator, Worksheet};
+pub use writable::WritableWorksheet;
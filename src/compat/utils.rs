@@ -150,6 +150,44 @@ pub fn coordinate_to_string(row: usize, col: usize) -> String {
     format!("{}{}", get_column_letter(col), row)
 }
 
+/// Convert an A1 range like "B2:C3" to its bounds
+///
+/// # Arguments
+///
+/// * `range` - Cell range like "B2:C3" or "$B$2:$C$3"; a single cell like
+///   "B2" is a one-cell range
+///
+/// # Returns
+///
+/// 1-indexed `(min_row, min_col, max_row, max_col)`, whichever corners the
+/// range is written with.
+///
+/// # Errors
+///
+/// Returns error if either corner is not a valid cell coordinate.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// assert_eq!(range_boundaries("B2:C3")?, (2, 2, 3, 3));
+/// assert_eq!(range_boundaries("$A$1")?, (1, 1, 1, 1));
+/// ```
+pub fn range_boundaries(range: &str) -> Result<(usize, usize, usize, usize)> {
+    let (first, last) = range.split_once(':').unwrap_or((range, range));
+    let corner = |coord: &str| {
+        coordinate_from_string(&coord.replace('$', ""))
+            .map_err(|_| Error::invalid_cell_reference(range))
+    };
+    let (first_row, first_col) = corner(first)?;
+    let (last_row, last_col) = corner(last)?;
+    Ok((
+        first_row.min(last_row),
+        first_col.min(last_col),
+        first_row.max(last_row),
+        first_col.max(last_col),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -233,6 +271,17 @@ mod tests {
         assert_eq!(coordinate_to_string(1_048_576, 16384), "XFD1048576");
     }
 
+    /// TDD RED: Test range bounds conversion
+    #[test]
+    fn test_range_boundaries() {
+        assert_eq!(range_boundaries("B2:C3").unwrap(), (2, 2, 3, 3));
+        assert_eq!(range_boundaries("$A$1:$AA$100").unwrap(), (1, 1, 100, 27));
+        assert_eq!(range_boundaries("C3:B2").unwrap(), (2, 2, 3, 3));
+        assert_eq!(range_boundaries("D4").unwrap(), (4, 4, 4, 4));
+        assert!(range_boundaries("B2:").is_err());
+        assert!(range_boundaries("Sheet1!B2:C3").is_err());
+    }
+
     /// TDD RED: Test round-trip conversion
     #[test]
     fn test_coordinate_roundtrip() {
@@ -8,6 +8,66 @@ use crate::error::{Error, Result};
 use crate::Reader;
 use std::path::Path;
 
+/// Options controlling how a workbook is loaded
+///
+/// Mirrors the `read_only` and `data_only` keyword arguments of `OpenPyXL`'s
+/// `load_workbook()`. Defaults match `OpenPyXL`: both flags are `false`.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use xlsxpress::compat::{load_workbook_with_options, LoadOptions};
+///
+/// let options = LoadOptions::new().read_only(true).data_only(true);
+/// let wb = load_workbook_with_options("data.xlsx", options)?;
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadOptions {
+    /// Open the workbook for reading only
+    read_only: bool,
+    /// Return cached formula results instead of formula strings
+    data_only: bool,
+}
+
+impl LoadOptions {
+    /// Create load options with `OpenPyXL` defaults
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether the workbook is opened read-only
+    ///
+    /// The compatibility layer never writes back to the source file, so this
+    /// is recorded for API parity and reported by `Workbook::read_only()`.
+    #[must_use]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Set whether formula cells return their cached values
+    ///
+    /// When `false`, formula cells return the formula string (e.g. `"=A1+B1"`).
+    #[must_use]
+    pub fn data_only(mut self, data_only: bool) -> Self {
+        self.data_only = data_only;
+        self
+    }
+
+    /// Check if the workbook is opened read-only
+    #[must_use]
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Check if formula cells return cached values
+    #[must_use]
+    pub fn is_data_only(&self) -> bool {
+        self.data_only
+    }
+}
+
 /// Workbook wrapper compatible with `OpenPyXL`
 ///
 /// Represents an Excel workbook with multiple worksheets.
@@ -15,6 +75,8 @@ use std::path::Path;
 pub struct Workbook {
     /// Internal reader
     reader: Reader,
+    /// Options the workbook was loaded with
+    options: LoadOptions,
 }
 
 impl Workbook {
@@ -25,7 +87,30 @@ impl Workbook {
     /// * `reader` - `XlsXpress` Reader instance
     #[must_use]
     pub fn new(reader: Reader) -> Self {
-        Self { reader }
+        Self::with_options(reader, LoadOptions::default())
+    }
+
+    /// Create a new workbook from a Reader with load options
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - `XlsXpress` Reader instance
+    /// * `options` - Load options (`read_only`, `data_only`)
+    #[must_use]
+    pub fn with_options(reader: Reader, options: LoadOptions) -> Self {
+        Self { reader, options }
+    }
+
+    /// Check if the workbook was opened read-only
+    #[must_use]
+    pub fn read_only(&self) -> bool {
+        self.options.is_read_only()
+    }
+
+    /// Check if formula cells return cached values instead of formulas
+    #[must_use]
+    pub fn data_only(&self) -> bool {
+        self.options.is_data_only()
     }
 
     /// Get list of worksheet names
@@ -61,7 +146,16 @@ impl Workbook {
     /// ```
     pub fn get_sheet_by_name(&mut self, name: &str) -> Result<Worksheet> {
         let range = self.reader.worksheet_range(name)?;
-        Ok(Worksheet::new(name, range))
+        let mut worksheet = Worksheet::new(name, range);
+        // Formats are only readable from xlsx packages; others stay "General"
+        if let Some(loader) = self.reader.number_format_loader(name) {
+            worksheet = worksheet.with_number_format_loader(loader);
+        }
+        if self.options.is_data_only() {
+            return Ok(worksheet);
+        }
+        let formulas = self.reader.worksheet_formula(name)?;
+        Ok(worksheet.with_formulas(formulas))
     }
 
     /// Get the active (first) worksheet
@@ -132,13 +226,42 @@ impl Workbook {
 /// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
 pub fn load_workbook<P: AsRef<Path>>(path: P) -> Result<Workbook> {
+    load_workbook_with_options(path, LoadOptions::default())
+}
+
+/// Load an Excel workbook from a file path with load options
+///
+/// Equivalent to `OpenPyXL`'s `load_workbook(path, read_only=..., data_only=...)`.
+///
+/// # Arguments
+///
+/// * `path` - Path to the Excel file
+/// * `options` - Load options (`read_only`, `data_only`)
+///
+/// # Errors
+///
+/// Returns error if file cannot be opened or is not a valid Excel file.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use xlsxpress::compat::{load_workbook_with_options, LoadOptions};
+///
+/// let wb = load_workbook_with_options("data.xlsx", LoadOptions::new().data_only(true))?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn load_workbook_with_options<P: AsRef<Path>>(
+    path: P,
+    options: LoadOptions,
+) -> Result<Workbook> {
     let reader = Reader::open(path)?;
-    Ok(Workbook::new(reader))
+    Ok(Workbook::with_options(reader, options))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::compat::cell::CellValue;
 
     /// TDD RED: Test `load_workbook` opens existing file
     #[test]
@@ -151,6 +274,22 @@ mod tests {
         );
     }
 
+    /// TDD RED: Test error cells are loaded as errors
+    #[test]
+    fn test_load_workbook_error_cells() {
+        let options = LoadOptions::new().data_only(true);
+        let mut wb = load_workbook_with_options("tests/fixtures/errors.xlsx", options).unwrap();
+        let ws = wb.active().unwrap();
+
+        let cell = ws.get_cell("B2").unwrap();
+        assert_eq!(cell.value(), &CellValue::Error("#DIV/0!".to_string()));
+        assert_eq!(cell.data_type(), 'e');
+        assert_eq!(
+            ws.get_cell("C2").unwrap().value(),
+            &CellValue::Error("#N/A".to_string())
+        );
+    }
+
     /// TDD RED: Test `load_workbook` fails for non-existent file
     #[test]
     fn test_load_workbook_nonexistent() {
@@ -219,6 +358,118 @@ mod tests {
         assert!(result.is_err());
     }
 
+    /// Helper: Write a workbook containing a formula cell
+    fn write_formula_fixture(path: &str) {
+        let mut writer = crate::Writer::new();
+        writer.add_worksheet("Sheet1").unwrap();
+        writer.write_number(0, 0, 0, 1.0).unwrap();
+        writer.write_number(0, 0, 1, 2.0).unwrap();
+        writer.write_formula(0, 0, 2, "=A1+B1").unwrap();
+        writer.save(path).unwrap();
+    }
+
+    /// TDD RED: Test load options defaults match `OpenPyXL`
+    #[test]
+    fn test_load_options_default() {
+        let options = LoadOptions::new();
+        assert!(!options.is_read_only());
+        assert!(!options.is_data_only());
+
+        let options = LoadOptions::new().read_only(true).data_only(true);
+        assert!(options.is_read_only());
+        assert!(options.is_data_only());
+    }
+
+    /// TDD RED: Test `data_only=False` returns formula strings
+    #[test]
+    fn test_load_workbook_formulas() {
+        let path = "tests/fixtures/compat_formulas_test.xlsx";
+        write_formula_fixture(path);
+
+        let options = LoadOptions::new().read_only(true).data_only(false);
+        let mut wb = load_workbook_with_options(path, options).unwrap();
+        assert!(wb.read_only());
+        assert!(!wb.data_only());
+
+        let ws = wb.active().unwrap();
+        let cell = ws.get_cell("C1").unwrap();
+        assert_eq!(cell.value(), &CellValue::String("=A1+B1".to_string()));
+        assert_eq!(ws.get_cell("A1").unwrap().value(), &CellValue::Number(1.0));
+
+        std::fs::remove_file(path).ok();
+    }
+
+    /// TDD RED: Test `data_only=True` returns cached values
+    #[test]
+    fn test_load_workbook_data_only() {
+        let path = "tests/fixtures/compat_data_only_test.xlsx";
+        write_formula_fixture(path);
+
+        let options = LoadOptions::new().read_only(false).data_only(true);
+        let mut wb = load_workbook_with_options(path, options).unwrap();
+        assert!(!wb.read_only());
+        assert!(wb.data_only());
+
+        let ws = wb.active().unwrap();
+        let cell = ws.get_cell("C1").unwrap();
+        assert!(!matches!(cell.value(), CellValue::String(_)));
+
+        std::fs::remove_file(path).ok();
+    }
+
+    /// TDD RED: Test reading a currency-formatted cell's number format
+    #[test]
+    fn test_cell_number_format() {
+        use crate::styles::{NumberFormat, Style};
+
+        // Arrange: Currency cell in B1 next to an unformatted cell in A1
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("number_format.xlsx");
+        let mut writer = crate::Writer::new();
+        writer.add_worksheet("Sheet1").unwrap();
+        writer.write_number(0, 0, 0, 1.0).unwrap();
+        let currency = Style::new().number_format(NumberFormat::currency(2));
+        writer
+            .write_number_with_style(0, 0, 1, 1234.5, &currency)
+            .unwrap();
+        writer.save(&path).unwrap();
+
+        // Act
+        let mut wb = load_workbook(&path).unwrap();
+        let ws = wb.active().unwrap();
+
+        // Assert
+        assert_eq!(ws.get_cell("B1").unwrap().number_format(), "$#,##0.00");
+        assert_eq!(ws.get_cell("A1").unwrap().number_format(), "General");
+    }
+
+    /// TDD RED: Test number formats are read when a cell is first accessed
+    #[test]
+    fn test_cell_number_format_loaded_lazily() {
+        // Arrange: Load the sheet, then remove the file it came from
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("lazy_formats.xlsx");
+        std::fs::copy("tests/fixtures/test.xlsx", &path).unwrap();
+        let mut wb = load_workbook(&path).unwrap();
+        let ws = wb.active().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        // Act
+        let result = ws.get_cell("A1");
+
+        // Assert: Formats weren't read up front, and the failure is reported
+        assert!(matches!(result, Err(Error::FileRead { .. })));
+    }
+
+    /// TDD RED: Test workbooks without number formats report "General"
+    #[test]
+    fn test_cell_number_format_ods() {
+        let mut wb = load_workbook("tests/fixtures/whitespace.ods").unwrap();
+        let ws = wb.get_sheet_by_name("People").unwrap();
+
+        assert_eq!(ws.get_cell("A1").unwrap().number_format(), "General");
+    }
+
     /// TDD RED: Test reading cell data through compat layer
     #[test]
     fn test_workbook_read_cell_data() {
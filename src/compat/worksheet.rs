@@ -6,7 +6,11 @@
 use crate::compat::cell::{Cell, CellValue};
 use crate::compat::utils::{coordinate_from_string, coordinate_to_string};
 use crate::error::{Error, Result};
-use calamine::{Data, Range};
+use crate::reader::NumberFormatLoader;
+use calamine::{Data, DataType, Range};
+use chrono::NaiveTime;
+use std::cell::OnceCell;
+use std::collections::HashMap;
 
 /// Worksheet wrapper compatible with `OpenPyXL`
 ///
@@ -17,6 +21,12 @@ pub struct Worksheet {
     title: String,
     /// Cell data range from calamine
     range: Range<Data>,
+    /// Formula range, present when formulas should be returned instead of values
+    formulas: Option<Range<String>>,
+    /// Number format codes of formatted cells, keyed by 0-indexed (row, col)
+    number_formats: OnceCell<HashMap<(u32, u32), String>>,
+    /// Reads the number formats when a cell is first accessed
+    number_format_loader: Option<NumberFormatLoader>,
 }
 
 impl Worksheet {
@@ -31,9 +41,46 @@ impl Worksheet {
         Self {
             title: title.into(),
             range,
+            formulas: None,
+            number_formats: OnceCell::new(),
+            number_format_loader: None,
         }
     }
 
+    /// Attach formulas so formula cells return their formula string
+    ///
+    /// Matches `OpenPyXL`'s `data_only=False` behavior, where a formula cell's
+    /// value is the formula (e.g. `"=SUM(A1:A3)"`) rather than its cached result.
+    ///
+    /// # Arguments
+    ///
+    /// * `formulas` - Formula range from calamine
+    #[must_use]
+    pub fn with_formulas(mut self, formulas: Range<String>) -> Self {
+        self.formulas = Some(formulas);
+        self
+    }
+
+    /// Attach cell number formats so cells report their `number_format`
+    ///
+    /// Cells missing from the map report "General".
+    ///
+    /// # Arguments
+    ///
+    /// * `number_formats` - Format codes keyed by 0-indexed (row, col)
+    #[must_use]
+    pub fn with_number_formats(mut self, number_formats: HashMap<(u32, u32), String>) -> Self {
+        self.number_formats = OnceCell::from(number_formats);
+        self
+    }
+
+    /// Read cell number formats from the package when a cell is first read
+    #[must_use]
+    pub(crate) fn with_number_format_loader(mut self, loader: NumberFormatLoader) -> Self {
+        self.number_format_loader = Some(loader);
+        self
+    }
+
     /// Get the worksheet title/name
     #[must_use]
     pub fn title(&self) -> &str {
@@ -64,6 +111,44 @@ impl Worksheet {
         self.range.get_size()
     }
 
+    /// Get the bounds of the cells that hold data
+    ///
+    /// Unlike [`Worksheet::max_row`] and [`Worksheet::max_column`], which
+    /// follow the stored range, trailing empty rows and columns are ignored,
+    /// like `OpenPyXL`'s `ws.calculate_dimension()`.
+    ///
+    /// Returns 1-indexed `(min_row, min_col, max_row, max_col)`, or all zeros
+    /// if the worksheet has no data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let (min_row, min_col, max_row, max_col) = ws.calculate_dimension();
+    /// ```
+    #[must_use]
+    pub fn calculate_dimension(&self) -> (usize, usize, usize, usize) {
+        let (start_row, start_col) = self.range.start().unwrap_or_default();
+        let (start_row, start_col) = (start_row as usize + 1, start_col as usize + 1);
+        self.range
+            .used_cells()
+            .map(|(row, col, _)| (start_row + row, start_col + col))
+            .fold(
+                None::<(usize, usize, usize, usize)>,
+                |bounds, (row, col)| {
+                    Some(match bounds {
+                        None => (row, col, row, col),
+                        Some((min_row, min_col, max_row, max_col)) => (
+                            min_row.min(row),
+                            min_col.min(col),
+                            max_row.max(row),
+                            max_col.max(col),
+                        ),
+                    })
+                },
+            )
+            .unwrap_or_default()
+    }
+
     /// Get a cell by coordinate string (e.g., "A1", "B2")
     ///
     /// # Arguments
@@ -72,7 +157,8 @@ impl Worksheet {
     ///
     /// # Errors
     ///
-    /// Returns error if coordinate is invalid.
+    /// Returns error if coordinate is invalid, or the sheet's number formats
+    /// can't be read.
     ///
     /// # Examples
     ///
@@ -93,7 +179,9 @@ impl Worksheet {
     ///
     /// # Errors
     ///
-    /// Returns error if coordinates are out of bounds.
+    /// Returns error if coordinates are out of bounds, or the sheet's number
+    /// formats can't be read. They are read from the file when the first
+    /// cell is accessed.
     ///
     /// # Examples
     ///
@@ -101,7 +189,6 @@ impl Worksheet {
     /// let cell = ws.cell(1, 1)?;  // A1
     /// let cell = ws.cell(2, 2)?;  // B2
     /// ```
-    #[allow(clippy::cast_precision_loss)]
     pub fn cell(&self, row: usize, column: usize) -> Result<Cell> {
         if row == 0 || column == 0 {
             return Err(Error::invalid_cell_reference(coordinate_to_string(
@@ -113,23 +200,81 @@ impl Worksheet {
         let row_idx = row - 1;
         let col_idx = column - 1;
 
-        // Get cell value from range
-        let value =
-            self.range
-                .get((row_idx, col_idx))
-                .map_or(CellValue::Empty, |data| match data {
-                    Data::String(s) => CellValue::String(s.clone()),
-                    Data::Float(f) => CellValue::Number(*f),
-                    Data::Int(i) => CellValue::Number(*i as f64),
-                    Data::Bool(b) => CellValue::Boolean(*b),
-                    Data::Empty
-                    | Data::Error(_)
-                    | Data::DateTime(_)
-                    | Data::DateTimeIso(_)
-                    | Data::DurationIso(_) => CellValue::Empty,
-                });
-
-        Ok(Cell::new(row, column, value))
+        let value = self
+            .formula_at(row_idx, col_idx)
+            .unwrap_or_else(|| self.value_at(row_idx, col_idx));
+
+        let cell = Cell::new(row, column, value);
+        Ok(match self.number_format_at(row_idx, col_idx)? {
+            Some(format) => cell.with_number_format(format),
+            None => cell,
+        })
+    }
+
+    /// Get the number format of a cell (0-indexed), if it has one
+    fn number_format_at(&self, row_idx: usize, col_idx: usize) -> Result<Option<&str>> {
+        let (Ok(row), Ok(col)) = (u32::try_from(row_idx), u32::try_from(col_idx)) else {
+            return Ok(None);
+        };
+        Ok(self.number_formats()?.get(&(row, col)).map(String::as_str))
+    }
+
+    /// Number formats of the sheet, loaded on first use
+    fn number_formats(&self) -> Result<&HashMap<(u32, u32), String>> {
+        if let Some(formats) = self.number_formats.get() {
+            return Ok(formats);
+        }
+        let formats = match &self.number_format_loader {
+            Some(loader) => loader.load()?,
+            None => HashMap::new(),
+        };
+        Ok(self.number_formats.get_or_init(|| formats))
+    }
+
+    /// Get the formula of a cell as a `CellValue` (0-indexed)
+    ///
+    /// Returns `None` if formulas are not attached or the cell has no formula.
+    #[allow(clippy::cast_possible_truncation)]
+    fn formula_at(&self, row_idx: usize, col_idx: usize) -> Option<CellValue> {
+        let formulas = self.formulas.as_ref()?;
+        formulas
+            .get_value((row_idx as u32, col_idx as u32))
+            .filter(|formula| !formula.is_empty())
+            .map(|formula| CellValue::String(format!("={formula}")))
+    }
+
+    /// Get the value of a cell as a `CellValue` (0-indexed)
+    #[allow(clippy::cast_precision_loss)]
+    fn value_at(&self, row_idx: usize, col_idx: usize) -> CellValue {
+        self.range
+            .get((row_idx, col_idx))
+            .map_or(CellValue::Empty, |data| match data {
+                Data::String(s) => CellValue::String(s.clone()),
+                Data::Float(f) => CellValue::Number(*f),
+                Data::Int(i) => CellValue::Number(*i as f64),
+                Data::Bool(b) => CellValue::Boolean(*b),
+                Data::DateTime(dt) if dt.is_duration() => Self::duration_value(data),
+                Data::DateTime(_) | Data::DateTimeIso(_) => {
+                    data.as_datetime().map_or(CellValue::Empty, |dt| {
+                        if dt.time() == NaiveTime::MIN {
+                            CellValue::Date(dt)
+                        } else {
+                            CellValue::DateTime(dt)
+                        }
+                    })
+                }
+                Data::DurationIso(_) => Self::duration_value(data),
+                Data::Error(e) => CellValue::Error(e.to_string()),
+                Data::Empty => CellValue::Empty,
+            })
+    }
+
+    /// Convert a duration cell to a `CellValue::Duration` in days
+    #[allow(clippy::cast_precision_loss)]
+    fn duration_value(data: &Data) -> CellValue {
+        data.as_duration().map_or(CellValue::Empty, |duration| {
+            CellValue::Duration(duration.num_milliseconds() as f64 / 86_400_000.0)
+        })
     }
 
     /// Iterate over rows in the worksheet
@@ -232,6 +377,22 @@ mod tests {
         assert_eq!(ws.dimensions(), (2, 3));
     }
 
+    /// TDD RED: Test used bounds ignore trailing blank rows and columns
+    #[test]
+    fn test_worksheet_calculate_dimension() {
+        let mut range = Range::new((0, 0), (9, 4));
+        range.set_value((1, 1), Data::String("Name".to_string()));
+        range.set_value((3, 2), Data::Float(7.0));
+        let ws = Worksheet::new("Sheet1", range);
+
+        assert_eq!(ws.max_row(), 10);
+        assert_eq!(ws.max_column(), 5);
+        assert_eq!(ws.calculate_dimension(), (2, 2, 4, 3));
+
+        let ws = Worksheet::new("Empty", Range::new((0, 0), (4, 4)));
+        assert_eq!(ws.calculate_dimension(), (0, 0, 0, 0));
+    }
+
     /// TDD RED: Test cell access by row/column
     #[test]
     fn test_worksheet_cell() {
@@ -296,6 +457,87 @@ mod tests {
         assert_eq!(cell.value(), &CellValue::Empty);
     }
 
+    /// TDD RED: Test attached formulas take precedence over cached values
+    #[test]
+    fn test_worksheet_with_formulas() {
+        use calamine::Cell as CalCell;
+
+        let range = create_test_range();
+        let formulas = Range::from_sparse(vec![CalCell::new((1, 1), "SUM(B1:B1)".to_string())]);
+        let ws = Worksheet::new("Sheet1", range).with_formulas(formulas);
+
+        // Formula cell returns the formula string
+        let cell = ws.get_cell("B2").unwrap();
+        assert_eq!(cell.value(), &CellValue::String("=SUM(B1:B1)".to_string()));
+
+        // Non-formula cell still returns its value
+        let cell = ws.get_cell("B1").unwrap();
+        assert_eq!(cell.value(), &CellValue::Number(42.0));
+    }
+
+    /// TDD RED: Test data types of loaded cells, including dates and formulas
+    #[test]
+    fn test_worksheet_cell_data_types() {
+        use calamine::{Cell as CalCell, ExcelDateTime, ExcelDateTimeType};
+
+        // 45366 is 2024-03-15 in the 1900 date system
+        let date = ExcelDateTime::new(45366.0, ExcelDateTimeType::DateTime, false);
+        let range = Range::from_sparse(vec![
+            CalCell::new((0, 0), Data::DateTime(date)),
+            CalCell::new((0, 1), Data::Float(2.0)),
+        ]);
+        let formulas = Range::from_sparse(vec![CalCell::new((0, 2), "B1*2".to_string())]);
+        let ws = Worksheet::new("Sheet1", range).with_formulas(formulas);
+
+        let cell = ws.get_cell("A1").unwrap();
+        assert_eq!(cell.data_type(), 'd');
+        assert!(matches!(cell.value(), CellValue::Date(_)));
+        assert_eq!(cell.value().to_string(), "2024-03-15 00:00:00");
+        assert_eq!(ws.get_cell("B1").unwrap().data_type(), 'n');
+        assert_eq!(ws.get_cell("C1").unwrap().data_type(), 'f');
+    }
+
+    /// TDD RED: Test datetime, duration and error cells keep their values
+    #[test]
+    fn test_worksheet_datetime_duration_error_cells() {
+        use calamine::{Cell as CalCell, CellErrorType, ExcelDateTime, ExcelDateTimeType};
+
+        // 45366.75 is 2024-03-15 18:00; 1.5 as a time delta is 36 hours
+        let datetime = ExcelDateTime::new(45366.75, ExcelDateTimeType::DateTime, false);
+        let duration = ExcelDateTime::new(1.5, ExcelDateTimeType::TimeDelta, false);
+        let range = Range::from_sparse(vec![
+            CalCell::new((0, 0), Data::DateTime(datetime)),
+            CalCell::new((0, 1), Data::DateTimeIso("2024-03-15T09:30:00".to_string())),
+            CalCell::new((0, 2), Data::DateTime(duration)),
+            CalCell::new((0, 3), Data::DurationIso("PT12H00M00S".to_string())),
+            CalCell::new((0, 4), Data::Error(CellErrorType::Div0)),
+        ]);
+        let ws = Worksheet::new("Sheet1", range);
+
+        let expected = chrono::NaiveDate::from_ymd_opt(2024, 3, 15)
+            .unwrap()
+            .and_hms_opt(18, 0, 0)
+            .unwrap();
+        assert_eq!(
+            ws.get_cell("A1").unwrap().value(),
+            &CellValue::DateTime(expected)
+        );
+        assert_eq!(
+            ws.get_cell("B1").unwrap().value().to_string(),
+            "2024-03-15 09:30:00"
+        );
+        let cell = ws.get_cell("C1").unwrap();
+        assert_eq!(cell.value(), &CellValue::Duration(1.5));
+        assert_eq!(cell.data_type(), 'd');
+        assert_eq!(
+            ws.get_cell("D1").unwrap().value(),
+            &CellValue::Duration(0.5)
+        );
+        let cell = ws.get_cell("E1").unwrap();
+        assert_eq!(cell.value(), &CellValue::Error("#DIV/0!".to_string()));
+        assert_eq!(cell.data_type(), 'e');
+    }
+
     /// TDD RED: Test row iterator
     #[test]
     fn test_worksheet_iter_rows() {
@@ -0,0 +1,157 @@
+//! Writable worksheet for `OpenPyXL` compatibility
+//!
+//! Provides a worksheet that is filled row by row with `append`, like
+//! `OpenPyXL`'s `ws.append([...])`, on top of the native Writer.
+
+use crate::compat::cell::CellValue;
+use crate::error::Result;
+use crate::writer::Writer;
+
+/// Writable worksheet compatible with `OpenPyXL`
+///
+/// Each call to [`WritableWorksheet::append`] writes one row below the
+/// previous one, starting at row 1.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use xlsxpress::compat::{CellValue, WritableWorksheet};
+/// use xlsxpress::Writer;
+///
+/// let mut writer = Writer::new();
+/// let mut ws = WritableWorksheet::new(&mut writer, "Sales")?;
+/// ws.append(vec!["Region".into(), "Total".into()])?;
+/// ws.append(vec!["North".into(), 1250.0.into()])?;
+/// writer.save("sales.xlsx")?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub struct WritableWorksheet<'a> {
+    /// Writer the worksheet belongs to
+    writer: &'a mut Writer,
+    /// Zero-based sheet index in the writer
+    sheet: usize,
+    /// Worksheet name/title
+    title: String,
+    /// Number of rows appended so far
+    rows: usize,
+}
+
+impl<'a> WritableWorksheet<'a> {
+    /// Add a new worksheet to a writer
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - Writer to add the worksheet to
+    /// * `title` - Name of the worksheet
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the worksheet cannot be created.
+    pub fn new(writer: &'a mut Writer, title: impl Into<String>) -> Result<Self> {
+        let title = title.into();
+        let sheet = writer.worksheet_count();
+        writer.add_worksheet(&title)?;
+        Ok(Self {
+            writer,
+            sheet,
+            title,
+            rows: 0,
+        })
+    }
+
+    /// Get worksheet title
+    #[must_use]
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// Get the last appended row (1-indexed), or 0 if none
+    #[must_use]
+    pub fn max_row(&self) -> usize {
+        self.rows
+    }
+
+    /// Write values to the next empty row, starting at column A
+    ///
+    /// Strings starting with "=" are written as formulas, matching
+    /// `OpenPyXL`. Durations are written as numbers of days and error
+    /// values as their text. `CellValue::Empty` leaves its cell blank.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - Cell values in column order
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::WriteLimitExceeded` if the row or a column exceeds
+    /// Excel limits. Returns error if a cell cannot be written.
+    pub fn append(&mut self, values: Vec<CellValue>) -> Result<()> {
+        let row = self.rows;
+        for (col, value) in values.into_iter().enumerate() {
+            match value {
+                CellValue::String(s) if s.starts_with('=') => {
+                    self.writer.write_formula(self.sheet, row, col, &s)?;
+                }
+                CellValue::String(s) => self.writer.write_string(self.sheet, row, col, &s)?,
+                CellValue::Number(n) => self.writer.write_number(self.sheet, row, col, n)?,
+                CellValue::Boolean(b) => self.writer.write_boolean(self.sheet, row, col, b)?,
+                CellValue::Date(d) | CellValue::DateTime(d) => {
+                    self.writer.write_datetime(self.sheet, row, col, d)?;
+                }
+                CellValue::Duration(days) => {
+                    self.writer.write_number(self.sheet, row, col, days)?;
+                }
+                CellValue::Error(e) => self.writer.write_string(self.sheet, row, col, &e)?,
+                CellValue::Empty => {}
+            }
+        }
+        self.rows += 1;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reader::Reader;
+    use calamine::Data;
+
+    /// TDD RED: Test appended rows are placed one below another
+    #[test]
+    fn test_append_rows() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("append.xlsx");
+        let mut writer = Writer::new();
+        let mut ws = WritableWorksheet::new(&mut writer, "Sales").unwrap();
+        assert_eq!(ws.title(), "Sales");
+        assert_eq!(ws.max_row(), 0);
+
+        // Act: Header, a mixed row and a row with a gap and a formula
+        ws.append(vec!["Region".into(), "Total".into(), "Closed".into()])
+            .unwrap();
+        ws.append(vec!["North".into(), 1250.0.into(), true.into()])
+            .unwrap();
+        ws.append(vec![CellValue::Empty, "=B2*2".into()]).unwrap();
+        assert_eq!(ws.max_row(), 3);
+        writer.save(&path).unwrap();
+
+        // Assert
+        let mut reader = Reader::open(&path).unwrap();
+        let range = reader.worksheet_range("Sales").unwrap();
+        assert_eq!(
+            range.get_value((0, 0)),
+            Some(&Data::String("Region".into()))
+        );
+        assert_eq!(
+            range.get_value((0, 2)),
+            Some(&Data::String("Closed".into()))
+        );
+        assert_eq!(range.get_value((1, 0)), Some(&Data::String("North".into())));
+        assert_eq!(range.get_value((1, 1)), Some(&Data::Float(1250.0)));
+        assert_eq!(range.get_value((1, 2)), Some(&Data::Bool(true)));
+        assert_eq!(range.get_value((2, 0)), Some(&Data::Empty));
+        let formulas = reader.worksheet_formula("Sales").unwrap();
+        assert_eq!(formulas.get_value((2, 1)), Some(&"B2*2".to_string()));
+    }
+}
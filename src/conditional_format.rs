@@ -0,0 +1,397 @@
+//! Conditional formatting
+//!
+//! Provides the rules that decide which cells of a range a conditional
+//! format styles.
+
+use crate::styles::Style;
+use rust_xlsxwriter::ConditionalFormatIconType;
+
+/// Rule deciding which cells a conditional format applies to
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use xlsxpress::conditional_format::ConditionalRule;
+/// use xlsxpress::styles::{Fill, Style};
+///
+/// // Shade every other row
+/// let zebra = ConditionalRule::formula(
+///     "=MOD(ROW(),2)=0",
+///     Style::new().fill(Fill::solid("#F2F2F2")),
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConditionalRule {
+    /// Style cells where a formula evaluates to true
+    ///
+    /// The formula is written relative to the range's top-left cell, e.g.
+    /// `=$B1>100` checks column B of each row.
+    Formula(String, Style),
+    /// Show an icon next to each value based on where it falls in the range
+    ///
+    /// The thresholds are the cell values at which each icon after the
+    /// first starts, in ascending order. Leave them empty to use Excel's
+    /// default percent bands.
+    IconSet(IconSetType, Vec<f64>),
+    /// Style cells whose value appears more than once in the range
+    Duplicate(Style),
+    /// Style cells whose value appears only once in the range
+    Unique(Style),
+    /// Shade each cell with a color between the scale's end colors
+    ColorScale(ColorScale),
+}
+
+/// Value a color scale point is placed at
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScaleValue {
+    /// Lowest value in the range, only for the minimum
+    Lowest,
+    /// Highest value in the range, only for the maximum
+    Highest,
+    /// Fixed number
+    Number(f64),
+    /// Percent of the way from the lowest to the highest value, 0 to 100
+    Percent(f64),
+    /// Percentile of the range's values, 0 to 100
+    Percentile(f64),
+    /// Result of a formula, e.g. `=$H$1`
+    Formula(String),
+}
+
+/// Point of a color scale
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScalePoint {
+    /// Where the point is placed
+    pub value: ScaleValue,
+    /// Color at the point, as hex (e.g. "#F8696B")
+    pub color: String,
+}
+
+impl ScalePoint {
+    fn new(value: ScaleValue, color: &str) -> Self {
+        Self {
+            value,
+            color: color.to_string(),
+        }
+    }
+}
+
+/// Two or three color scale for [`ConditionalRule::ColorScale`]
+///
+/// By default the minimum is the lowest value in the range, the maximum the
+/// highest and a three color scale's midpoint the 50th percentile.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use xlsxpress::conditional_format::{ColorScale, ConditionalRule, ScaleValue};
+///
+/// // Red below the 10th percentile, green above the 90th
+/// let scale = ColorScale::three_color("#F8696B", "#FFEB84", "#63BE7B")
+///     .min(ScaleValue::Percentile(10.0))
+///     .max(ScaleValue::Percentile(90.0));
+/// let rule = ConditionalRule::color_scale(scale);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColorScale {
+    /// Lowest point of the scale
+    min: ScalePoint,
+    /// Middle point, for three color scales
+    mid: Option<ScalePoint>,
+    /// Highest point of the scale
+    max: ScalePoint,
+}
+
+impl ColorScale {
+    /// Create a two color scale
+    ///
+    /// # Arguments
+    ///
+    /// * `min_color` - Color of the minimum, as hex
+    /// * `max_color` - Color of the maximum, as hex
+    #[must_use]
+    pub fn two_color(min_color: &str, max_color: &str) -> Self {
+        Self {
+            min: ScalePoint::new(ScaleValue::Lowest, min_color),
+            mid: None,
+            max: ScalePoint::new(ScaleValue::Highest, max_color),
+        }
+    }
+
+    /// Create a three color scale
+    ///
+    /// # Arguments
+    ///
+    /// * `low_color` - Color of the minimum, as hex
+    /// * `middle_color` - Color of the midpoint, as hex
+    /// * `high_color` - Color of the maximum, as hex
+    #[must_use]
+    pub fn three_color(low_color: &str, middle_color: &str, high_color: &str) -> Self {
+        Self {
+            min: ScalePoint::new(ScaleValue::Lowest, low_color),
+            mid: Some(ScalePoint::new(ScaleValue::Percentile(50.0), middle_color)),
+            max: ScalePoint::new(ScaleValue::Highest, high_color),
+        }
+    }
+
+    /// Set where the minimum is placed
+    #[must_use]
+    pub fn min(mut self, value: ScaleValue) -> Self {
+        self.min.value = value;
+        self
+    }
+
+    /// Set where the midpoint is placed
+    ///
+    /// Has no effect on a two color scale.
+    #[must_use]
+    pub fn mid(mut self, value: ScaleValue) -> Self {
+        if let Some(mid) = &mut self.mid {
+            mid.value = value;
+        }
+        self
+    }
+
+    /// Set where the maximum is placed
+    #[must_use]
+    pub fn max(mut self, value: ScaleValue) -> Self {
+        self.max.value = value;
+        self
+    }
+
+    /// Get the minimum point
+    #[must_use]
+    pub fn get_min(&self) -> &ScalePoint {
+        &self.min
+    }
+
+    /// Get the midpoint, if this is a three color scale
+    #[must_use]
+    pub fn get_mid(&self) -> Option<&ScalePoint> {
+        self.mid.as_ref()
+    }
+
+    /// Get the maximum point
+    #[must_use]
+    pub fn get_max(&self) -> &ScalePoint {
+        &self.max
+    }
+}
+
+/// Icon styles available to an icon set rule
+///
+/// The icons run from the lowest values to the highest, e.g. red, yellow,
+/// then green for `ThreeTrafficLights`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IconSetType {
+    /// Three arrows
+    ThreeArrows,
+    /// Three gray arrows
+    ThreeArrowsGray,
+    /// Three flags
+    ThreeFlags,
+    /// Three traffic lights without rims
+    ThreeTrafficLights,
+    /// Three traffic lights with rims
+    ThreeTrafficLightsWithRim,
+    /// Three signs
+    ThreeSigns,
+    /// Three circled symbols
+    ThreeSymbolsCircled,
+    /// Three uncircled symbols
+    ThreeSymbols,
+    /// Three stars
+    ThreeStars,
+    /// Three triangles
+    ThreeTriangles,
+    /// Four arrows
+    FourArrows,
+    /// Four gray arrows
+    FourArrowsGray,
+    /// Four circles from red to black
+    FourRedToBlack,
+    /// Four histogram bars
+    FourHistograms,
+    /// Four traffic lights
+    FourTrafficLights,
+    /// Five arrows
+    FiveArrows,
+    /// Five gray arrows
+    FiveArrowsGray,
+    /// Five histogram bars
+    FiveHistograms,
+    /// Five quadrants
+    FiveQuadrants,
+    /// Five boxes
+    FiveBoxes,
+}
+
+impl IconSetType {
+    /// Get the number of icons in the set
+    #[must_use]
+    pub fn icon_count(self) -> usize {
+        match self {
+            Self::ThreeArrows
+            | Self::ThreeArrowsGray
+            | Self::ThreeFlags
+            | Self::ThreeTrafficLights
+            | Self::ThreeTrafficLightsWithRim
+            | Self::ThreeSigns
+            | Self::ThreeSymbolsCircled
+            | Self::ThreeSymbols
+            | Self::ThreeStars
+            | Self::ThreeTriangles => 3,
+            Self::FourArrows
+            | Self::FourArrowsGray
+            | Self::FourRedToBlack
+            | Self::FourHistograms
+            | Self::FourTrafficLights => 4,
+            Self::FiveArrows
+            | Self::FiveArrowsGray
+            | Self::FiveHistograms
+            | Self::FiveQuadrants
+            | Self::FiveBoxes => 5,
+        }
+    }
+}
+
+impl From<IconSetType> for ConditionalFormatIconType {
+    fn from(icon_type: IconSetType) -> Self {
+        match icon_type {
+            IconSetType::ThreeArrows => Self::ThreeArrows,
+            IconSetType::ThreeArrowsGray => Self::ThreeArrowsGray,
+            IconSetType::ThreeFlags => Self::ThreeFlags,
+            IconSetType::ThreeTrafficLights => Self::ThreeTrafficLights,
+            IconSetType::ThreeTrafficLightsWithRim => Self::ThreeTrafficLightsWithRim,
+            IconSetType::ThreeSigns => Self::ThreeSigns,
+            IconSetType::ThreeSymbolsCircled => Self::ThreeSymbolsCircled,
+            IconSetType::ThreeSymbols => Self::ThreeSymbols,
+            IconSetType::ThreeStars => Self::ThreeStars,
+            IconSetType::ThreeTriangles => Self::ThreeTriangles,
+            IconSetType::FourArrows => Self::FourArrows,
+            IconSetType::FourArrowsGray => Self::FourArrowsGray,
+            IconSetType::FourRedToBlack => Self::FourRedToBlack,
+            IconSetType::FourHistograms => Self::FourHistograms,
+            IconSetType::FourTrafficLights => Self::FourTrafficLights,
+            IconSetType::FiveArrows => Self::FiveArrows,
+            IconSetType::FiveArrowsGray => Self::FiveArrowsGray,
+            IconSetType::FiveHistograms => Self::FiveHistograms,
+            IconSetType::FiveQuadrants => Self::FiveQuadrants,
+            IconSetType::FiveBoxes => Self::FiveBoxes,
+        }
+    }
+}
+
+impl ConditionalRule {
+    /// Create a formula rule
+    #[must_use]
+    pub fn formula(formula: impl Into<String>, style: Style) -> Self {
+        Self::Formula(formula.into(), style)
+    }
+
+    /// Create a rule highlighting duplicated values
+    #[must_use]
+    pub fn duplicate(style: Style) -> Self {
+        Self::Duplicate(style)
+    }
+
+    /// Create a rule highlighting unique values
+    #[must_use]
+    pub fn unique(style: Style) -> Self {
+        Self::Unique(style)
+    }
+
+    /// Create a color scale rule
+    #[must_use]
+    pub fn color_scale(scale: ColorScale) -> Self {
+        Self::ColorScale(scale)
+    }
+
+    /// Create an icon set rule with Excel's default thresholds
+    #[must_use]
+    pub fn icon_set(icon_type: IconSetType) -> Self {
+        Self::IconSet(icon_type, Vec::new())
+    }
+
+    /// Create an icon set rule with explicit value thresholds
+    ///
+    /// Pass one threshold per icon after the first, e.g. `&[40.0, 70.0]`
+    /// for a three-icon set.
+    #[must_use]
+    pub fn icon_set_with_thresholds(icon_type: IconSetType, thresholds: &[f64]) -> Self {
+        Self::IconSet(icon_type, thresholds.to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test creating a formula rule
+    #[test]
+    fn test_formula_rule() {
+        let rule = ConditionalRule::formula("=MOD(ROW(),2)=0", Style::new());
+        assert_eq!(
+            rule,
+            ConditionalRule::Formula("=MOD(ROW(),2)=0".to_string(), Style::new())
+        );
+    }
+
+    /// TDD RED: Test creating duplicate and unique rules
+    #[test]
+    fn test_duplicate_and_unique_rules() {
+        assert_eq!(
+            ConditionalRule::duplicate(Style::new()),
+            ConditionalRule::Duplicate(Style::new())
+        );
+        assert_eq!(
+            ConditionalRule::unique(Style::new()),
+            ConditionalRule::Unique(Style::new())
+        );
+    }
+
+    /// TDD RED: Test creating icon set rules
+    #[test]
+    fn test_icon_set_rule() {
+        assert_eq!(
+            ConditionalRule::icon_set(IconSetType::FiveArrows),
+            ConditionalRule::IconSet(IconSetType::FiveArrows, Vec::new())
+        );
+        assert_eq!(
+            ConditionalRule::icon_set_with_thresholds(
+                IconSetType::ThreeTrafficLights,
+                &[40.0, 70.0]
+            ),
+            ConditionalRule::IconSet(IconSetType::ThreeTrafficLights, vec![40.0, 70.0])
+        );
+        assert_eq!(IconSetType::ThreeTrafficLights.icon_count(), 3);
+        assert_eq!(IconSetType::FourHistograms.icon_count(), 4);
+        assert_eq!(IconSetType::FiveArrows.icon_count(), 5);
+    }
+
+    /// TDD RED: Test building color scales
+    #[test]
+    fn test_color_scale_builder() {
+        let two = ColorScale::two_color("#FFFFFF", "#63BE7B").mid(ScaleValue::Number(5.0));
+        assert_eq!(two.get_min().value, ScaleValue::Lowest);
+        assert_eq!(two.get_max().color, "#63BE7B");
+        assert_eq!(two.get_mid(), None);
+
+        let three = ColorScale::three_color("#F8696B", "#FFEB84", "#63BE7B");
+        assert_eq!(three.get_mid().unwrap().value, ScaleValue::Percentile(50.0));
+        let three = three
+            .min(ScaleValue::Number(0.0))
+            .mid(ScaleValue::Formula("=$H$1".to_string()))
+            .max(ScaleValue::Percent(80.0));
+        assert_eq!(three.get_min().value, ScaleValue::Number(0.0));
+        assert_eq!(
+            three.get_mid().unwrap().value,
+            ScaleValue::Formula("=$H$1".to_string())
+        );
+        assert_eq!(three.get_max().value, ScaleValue::Percent(80.0));
+        assert!(matches!(
+            ConditionalRule::color_scale(three),
+            ConditionalRule::ColorScale(_)
+        ));
+    }
+}
@@ -0,0 +1,636 @@
+//! Decryption of password-protected workbooks
+//!
+//! Encrypted .xlsx files are not ZIP archives but OLE compound files holding
+//! an `EncryptionInfo` stream and an `EncryptedPackage` stream, as described
+//! in MS-OFFCRYPTO. This module supports Agile encryption (Excel 2010 and
+//! later) and Standard ECMA-376 encryption (Excel 2007), and returns the
+//! decrypted workbook bytes so they can be read by calamine.
+
+use crate::error::{Error, Result};
+use aes::cipher::block_padding::NoPadding;
+use aes::cipher::{BlockCipher, BlockDecryptMut, KeyInit, KeyIvInit};
+use aes::{Aes128, Aes192, Aes256};
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader as XmlReader;
+use sha1::{Digest, Sha1};
+use sha2::{Sha256, Sha384, Sha512};
+use std::collections::HashMap;
+use std::io::{Cursor, Read};
+
+/// Signature at the start of every OLE compound file
+const CFB_SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+
+/// Block keys used to derive the Agile encryption keys
+const VERIFIER_INPUT_BLOCK: [u8; 8] = [0xFE, 0xA7, 0xD2, 0x76, 0x3B, 0x4B, 0x9E, 0x79];
+const VERIFIER_HASH_BLOCK: [u8; 8] = [0xD7, 0xAA, 0x0F, 0x6D, 0x30, 0x61, 0x34, 0x4E];
+const KEY_VALUE_BLOCK: [u8; 8] = [0x14, 0x6E, 0x0B, 0xE7, 0xAB, 0xAC, 0xD0, 0xD6];
+const HMAC_KEY_BLOCK: [u8; 8] = [0x5F, 0xB2, 0xAD, 0x01, 0x0C, 0xB9, 0xE1, 0xF6];
+const HMAC_VALUE_BLOCK: [u8; 8] = [0xA0, 0x67, 0x7F, 0x02, 0xB2, 0x2C, 0x84, 0x33];
+
+/// Size of each independently encrypted segment of an Agile package
+const SEGMENT_SIZE: usize = 4096;
+
+/// Spin count used by Standard encryption
+const STANDARD_SPIN_COUNT: u32 = 50_000;
+
+/// Highest Agile spin count the specification allows
+const MAX_SPIN_COUNT: u32 = 10_000_000;
+
+/// Check whether the data looks like an encrypted workbook
+///
+/// Returns `true` for OLE compound files containing an `EncryptionInfo`
+/// stream. Plain .xlsx files (ZIP archives) return `false`.
+#[must_use]
+pub fn is_encrypted(data: &[u8]) -> bool {
+    data.starts_with(&CFB_SIGNATURE)
+        && cfb::CompoundFile::open(Cursor::new(data))
+            .is_ok_and(|compound| compound.is_stream("/EncryptionInfo"))
+}
+
+/// Decrypt a password-protected workbook
+///
+/// # Arguments
+///
+/// * `data` - Raw bytes of the encrypted file
+/// * `password` - Password used to encrypt the workbook
+///
+/// # Errors
+///
+/// Returns `Error::Other("incorrect password")` if the password is wrong.
+/// Returns `Error::InvalidFormat` if the file is not an encrypted workbook,
+/// uses an unsupported encryption scheme, asks for more than 10,000,000
+/// password hashing rounds, or fails its integrity check.
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// let data = std::fs::read("protected.xlsx")?;
+/// let decrypted = xlsxpress::encryption::decrypt(&data, "secret")?;
+/// ```
+pub fn decrypt(data: &[u8], password: &str) -> Result<Vec<u8>> {
+    let mut compound = cfb::CompoundFile::open(Cursor::new(data))
+        .map_err(|_| Error::invalid_format("not an encrypted workbook"))?;
+    let info = read_stream(&mut compound, "/EncryptionInfo")?;
+    let package = read_stream(&mut compound, "/EncryptedPackage")?;
+
+    let body = info
+        .get(8..)
+        .ok_or_else(|| Error::invalid_format("truncated encryption info"))?;
+    match (read_u16(&info, 0)?, read_u16(&info, 2)?) {
+        (4, 4) => decrypt_agile(body, &package, password),
+        (2..=4, 2) => decrypt_standard(body, &package, password),
+        (major, minor) => Err(Error::invalid_format(format!(
+            "unsupported encryption version {major}.{minor}"
+        ))),
+    }
+}
+
+/// Read a whole stream from the compound file
+fn read_stream(compound: &mut cfb::CompoundFile<Cursor<&[u8]>>, path: &str) -> Result<Vec<u8>> {
+    let mut stream = compound
+        .open_stream(path)
+        .map_err(|_| Error::invalid_format(format!("missing {} stream", &path[1..])))?;
+    let mut buffer = Vec::new();
+    stream.read_to_end(&mut buffer)?;
+    Ok(buffer)
+}
+
+/// Hash algorithms allowed by Agile encryption
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HashAlgorithm {
+    Sha1,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    /// Parse the `hashAlgorithm` attribute value
+    fn parse(name: &str) -> Result<Self> {
+        match name {
+            "SHA1" | "SHA-1" => Ok(Self::Sha1),
+            "SHA256" => Ok(Self::Sha256),
+            "SHA384" => Ok(Self::Sha384),
+            "SHA512" => Ok(Self::Sha512),
+            other => Err(Error::invalid_format(format!(
+                "unsupported hash algorithm {other}"
+            ))),
+        }
+    }
+
+    /// Hash the concatenation of `parts`
+    fn digest(self, parts: &[&[u8]]) -> Vec<u8> {
+        match self {
+            Self::Sha1 => digest_with::<Sha1>(parts),
+            Self::Sha256 => digest_with::<Sha256>(parts),
+            Self::Sha384 => digest_with::<Sha384>(parts),
+            Self::Sha512 => digest_with::<Sha512>(parts),
+        }
+    }
+
+    /// Compute an HMAC of `data` keyed with `key`
+    fn hmac(self, key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+        match self {
+            Self::Sha1 => hmac_with::<Hmac<Sha1>>(key, data),
+            Self::Sha256 => hmac_with::<Hmac<Sha256>>(key, data),
+            Self::Sha384 => hmac_with::<Hmac<Sha384>>(key, data),
+            Self::Sha512 => hmac_with::<Hmac<Sha512>>(key, data),
+        }
+    }
+}
+
+fn digest_with<D: Digest>(parts: &[&[u8]]) -> Vec<u8> {
+    let mut hasher = D::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().to_vec()
+}
+
+fn hmac_with<M: Mac + KeyInit>(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    let mut mac =
+        <M as Mac>::new_from_slice(key).map_err(|_| Error::invalid_format("invalid HMAC key"))?;
+    mac.update(data);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+/// Hash the password with its salt, then re-hash it `spin_count` times
+fn hash_password(hash: HashAlgorithm, salt: &[u8], password: &str, spin_count: u32) -> Vec<u8> {
+    let password: Vec<u8> = password.encode_utf16().flat_map(u16::to_le_bytes).collect();
+    let mut hashed = hash.digest(&[salt, &password]);
+    for iteration in 0..spin_count {
+        hashed = hash.digest(&[&iteration.to_le_bytes(), &hashed]);
+    }
+    hashed
+}
+
+/// Resize a derived value, padding with `0x36` as MS-OFFCRYPTO requires
+fn fit(mut value: Vec<u8>, len: usize) -> Vec<u8> {
+    value.resize(len, 0x36);
+    value
+}
+
+/// Decrypt AES-CBC data without padding, picking the AES variant by key size
+fn aes_cbc_decrypt(key: &[u8], iv: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    match key.len() {
+        16 => cbc_decrypt::<Aes128>(key, iv, data),
+        24 => cbc_decrypt::<Aes192>(key, iv, data),
+        32 => cbc_decrypt::<Aes256>(key, iv, data),
+        len => Err(Error::invalid_format(format!(
+            "unsupported AES key size {len}"
+        ))),
+    }
+}
+
+fn cbc_decrypt<C>(key: &[u8], iv: &[u8], data: &[u8]) -> Result<Vec<u8>>
+where
+    C: BlockDecryptMut + BlockCipher + KeyInit,
+{
+    let mut buffer = data.to_vec();
+    cbc::Decryptor::<C>::new_from_slices(key, iv)
+        .map_err(|_| Error::invalid_format("invalid AES key or IV length"))?
+        .decrypt_padded_mut::<NoPadding>(&mut buffer)
+        .map_err(|_| Error::invalid_format("encrypted data is not block aligned"))?;
+    Ok(buffer)
+}
+
+/// Decrypt AES-ECB data without padding, picking the AES variant by key size
+fn aes_ecb_decrypt(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    match key.len() {
+        16 => ecb_decrypt::<Aes128>(key, data),
+        24 => ecb_decrypt::<Aes192>(key, data),
+        32 => ecb_decrypt::<Aes256>(key, data),
+        len => Err(Error::invalid_format(format!(
+            "unsupported AES key size {len}"
+        ))),
+    }
+}
+
+fn ecb_decrypt<C>(key: &[u8], data: &[u8]) -> Result<Vec<u8>>
+where
+    C: BlockDecryptMut + BlockCipher + KeyInit,
+{
+    let mut buffer = data.to_vec();
+    ecb::Decryptor::<C>::new_from_slice(key)
+        .map_err(|_| Error::invalid_format("invalid AES key length"))?
+        .decrypt_padded_mut::<NoPadding>(&mut buffer)
+        .map_err(|_| Error::invalid_format("encrypted data is not block aligned"))?;
+    Ok(buffer)
+}
+
+fn incorrect_password() -> Error {
+    Error::Other("incorrect password".to_string())
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16> {
+    data.get(offset..offset + 2)
+        .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]))
+        .ok_or_else(|| Error::invalid_format("truncated encryption info"))
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Result<u32> {
+    data.get(offset..offset + 4)
+        .map(|bytes| u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .ok_or_else(|| Error::invalid_format("truncated encryption info"))
+}
+
+fn read_bytes(data: &[u8], offset: usize, len: usize) -> Result<&[u8]> {
+    data.get(offset..offset + len)
+        .ok_or_else(|| Error::invalid_format("truncated encryption info"))
+}
+
+/// Split an `EncryptedPackage` stream into its declared size and payload
+#[allow(clippy::cast_possible_truncation)]
+fn split_package(package: &[u8]) -> Result<(usize, &[u8])> {
+    let size = package
+        .get(..8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .ok_or_else(|| Error::invalid_format("truncated encrypted package"))?;
+    Ok((size as usize, &package[8..]))
+}
+
+/// Cipher parameters shared by the Agile `keyData` and `encryptedKey` elements
+#[derive(Debug)]
+struct CipherParams {
+    salt: Vec<u8>,
+    block_size: usize,
+    key_bytes: usize,
+    hash_size: usize,
+    hash: HashAlgorithm,
+}
+
+impl CipherParams {
+    fn from_attributes(attrs: &HashMap<String, String>) -> Result<Self> {
+        if attr(attrs, "cipherAlgorithm")? != "AES" {
+            return Err(Error::invalid_format("only AES encryption is supported"));
+        }
+        if attr(attrs, "cipherChaining")? != "ChainingModeCBC" {
+            return Err(Error::invalid_format("only CBC chaining is supported"));
+        }
+        Ok(Self {
+            salt: base64_attr(attrs, "saltValue")?,
+            block_size: number_attr(attrs, "blockSize")?,
+            key_bytes: number_attr::<usize>(attrs, "keyBits")? / 8,
+            hash_size: number_attr(attrs, "hashSize")?,
+            hash: HashAlgorithm::parse(attr(attrs, "hashAlgorithm")?)?,
+        })
+    }
+
+    /// IV derived from the salt and a block key, as used for the package and HMAC
+    fn derived_iv(&self, block: &[u8]) -> Vec<u8> {
+        fit(self.hash.digest(&[&self.salt, block]), self.block_size)
+    }
+}
+
+/// Password key encryptor of an Agile `EncryptionInfo`
+#[derive(Debug)]
+struct PasswordKeyEncryptor {
+    params: CipherParams,
+    spin_count: u32,
+    verifier_hash_input: Vec<u8>,
+    verifier_hash_value: Vec<u8>,
+    key_value: Vec<u8>,
+}
+
+/// Parsed Agile `EncryptionInfo` descriptor
+#[derive(Debug)]
+struct AgileInfo {
+    key_data: CipherParams,
+    hmac_key: Option<Vec<u8>>,
+    hmac_value: Option<Vec<u8>>,
+    password: PasswordKeyEncryptor,
+}
+
+fn attr<'a>(attrs: &'a HashMap<String, String>, name: &str) -> Result<&'a str> {
+    attrs
+        .get(name)
+        .map(String::as_str)
+        .ok_or_else(|| Error::invalid_format(format!("missing {name} attribute")))
+}
+
+fn number_attr<T: std::str::FromStr>(attrs: &HashMap<String, String>, name: &str) -> Result<T> {
+    attr(attrs, name)?
+        .parse()
+        .map_err(|_| Error::invalid_format(format!("invalid {name} attribute")))
+}
+
+fn base64_attr(attrs: &HashMap<String, String>, name: &str) -> Result<Vec<u8>> {
+    base64::engine::general_purpose::STANDARD
+        .decode(attr(attrs, name)?)
+        .map_err(|_| Error::invalid_format(format!("invalid {name} attribute")))
+}
+
+fn element_attributes(
+    element: &BytesStart<'_>,
+    reader: &XmlReader<&[u8]>,
+) -> HashMap<String, String> {
+    element
+        .attributes()
+        .flatten()
+        .filter_map(|a| {
+            let key = String::from_utf8(a.key.local_name().as_ref().to_vec()).ok()?;
+            let value = a.decode_and_unescape_value(reader).ok()?.into_owned();
+            Some((key, value))
+        })
+        .collect()
+}
+
+/// Collect the attributes of the descriptor elements we need, by local name
+fn parse_agile_xml(xml: &str) -> Result<HashMap<String, HashMap<String, String>>> {
+    let mut reader = XmlReader::from_str(xml);
+    let mut elements = HashMap::new();
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e) | Event::Empty(e)) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+                let attrs = element_attributes(&e, &reader);
+                // Certificate key encryptors have no spin count; skip them
+                if name != "encryptedKey" || attrs.contains_key("spinCount") {
+                    elements.entry(name).or_insert(attrs);
+                }
+            }
+            Ok(Event::Eof) => return Ok(elements),
+            Err(e) => {
+                return Err(Error::invalid_format(format!(
+                    "invalid encryption XML: {e}"
+                )))
+            }
+            Ok(_) => {}
+        }
+    }
+}
+
+impl AgileInfo {
+    fn parse(descriptor: &[u8]) -> Result<Self> {
+        let xml = std::str::from_utf8(descriptor)
+            .map_err(|_| Error::invalid_format("encryption descriptor is not UTF-8"))?;
+        let elements = parse_agile_xml(xml)?;
+        let element = |name: &str| {
+            elements
+                .get(name)
+                .ok_or_else(|| Error::invalid_format(format!("missing {name} element")))
+        };
+
+        let key = element("encryptedKey")?;
+        let spin_count = number_attr(key, "spinCount")?;
+        if spin_count > MAX_SPIN_COUNT {
+            return Err(Error::invalid_format(format!(
+                "spin count {spin_count} exceeds {MAX_SPIN_COUNT}"
+            )));
+        }
+        let integrity = elements.get("dataIntegrity");
+        Ok(Self {
+            key_data: CipherParams::from_attributes(element("keyData")?)?,
+            hmac_key: integrity
+                .map(|a| base64_attr(a, "encryptedHmacKey"))
+                .transpose()?,
+            hmac_value: integrity
+                .map(|a| base64_attr(a, "encryptedHmacValue"))
+                .transpose()?,
+            password: PasswordKeyEncryptor {
+                params: CipherParams::from_attributes(key)?,
+                spin_count,
+                verifier_hash_input: base64_attr(key, "encryptedVerifierHashInput")?,
+                verifier_hash_value: base64_attr(key, "encryptedVerifierHashValue")?,
+                key_value: base64_attr(key, "encryptedKeyValue")?,
+            },
+        })
+    }
+}
+
+impl PasswordKeyEncryptor {
+    /// Verify the password and return the intermediate key for the package
+    fn unlock(&self, password: &str) -> Result<Vec<u8>> {
+        let params = &self.params;
+        let hashed = hash_password(params.hash, &params.salt, password, self.spin_count);
+        let decrypt = |block: &[u8], data: &[u8]| {
+            let key = fit(params.hash.digest(&[&hashed, block]), params.key_bytes);
+            aes_cbc_decrypt(&key, &fit(params.salt.clone(), params.block_size), data)
+        };
+
+        let mut input = decrypt(&VERIFIER_INPUT_BLOCK, &self.verifier_hash_input)?;
+        input.truncate(params.salt.len());
+        let mut expected = decrypt(&VERIFIER_HASH_BLOCK, &self.verifier_hash_value)?;
+        expected.truncate(params.hash_size);
+        if params.hash.digest(&[&input]) != expected {
+            return Err(incorrect_password());
+        }
+
+        let mut key = decrypt(&KEY_VALUE_BLOCK, &self.key_value)?;
+        key.truncate(params.key_bytes);
+        Ok(key)
+    }
+}
+
+/// Check the package HMAC, if the descriptor carries one
+fn verify_integrity(info: &AgileInfo, key: &[u8], package: &[u8]) -> Result<()> {
+    let (Some(hmac_key), Some(hmac_value)) = (&info.hmac_key, &info.hmac_value) else {
+        return Ok(());
+    };
+    let params = &info.key_data;
+    let mut hmac_key = aes_cbc_decrypt(key, &params.derived_iv(&HMAC_KEY_BLOCK), hmac_key)?;
+    hmac_key.truncate(params.hash_size);
+    let mut expected = aes_cbc_decrypt(key, &params.derived_iv(&HMAC_VALUE_BLOCK), hmac_value)?;
+    expected.truncate(params.hash_size);
+
+    if params.hash.hmac(&hmac_key, package)? == expected {
+        Ok(())
+    } else {
+        Err(Error::invalid_format(
+            "encrypted package failed its integrity check",
+        ))
+    }
+}
+
+/// Decrypt an Agile encrypted package
+#[allow(clippy::cast_possible_truncation)]
+fn decrypt_agile(descriptor: &[u8], package: &[u8], password: &str) -> Result<Vec<u8>> {
+    let info = AgileInfo::parse(descriptor)?;
+    let key = info.password.unlock(password)?;
+    verify_integrity(&info, &key, package)?;
+
+    let (size, payload) = split_package(package)?;
+    let mut output = Vec::with_capacity(payload.len());
+    for (index, segment) in payload.chunks(SEGMENT_SIZE).enumerate() {
+        let iv = info.key_data.derived_iv(&(index as u32).to_le_bytes());
+        output.extend(aes_cbc_decrypt(&key, &iv, segment)?);
+    }
+    output.truncate(size);
+    Ok(output)
+}
+
+/// Derive the Standard encryption key from the password
+fn standard_key(salt: &[u8], password: &str, key_bytes: usize) -> Vec<u8> {
+    let hash = HashAlgorithm::Sha1;
+    let hashed = hash_password(hash, salt, password, STANDARD_SPIN_COUNT);
+    let derived = hash.digest(&[&hashed, &0u32.to_le_bytes()]);
+
+    let xor_pad = |fill: u8| {
+        let mut buffer = [fill; 64];
+        for (byte, value) in buffer.iter_mut().zip(&derived) {
+            *byte ^= value;
+        }
+        hash.digest(&[&buffer])
+    };
+    let mut key = xor_pad(0x36);
+    key.extend(xor_pad(0x5C));
+    key.truncate(key_bytes);
+    key
+}
+
+/// Decrypt a Standard (ECMA-376) encrypted package
+fn decrypt_standard(info: &[u8], package: &[u8], password: &str) -> Result<Vec<u8>> {
+    let header_size = read_u32(info, 0)? as usize;
+    let header = read_bytes(info, 4, header_size)?;
+    if !matches!(read_u32(header, 8)?, 0x660E..=0x6610) {
+        return Err(Error::invalid_format("only AES encryption is supported"));
+    }
+    let key_bytes = read_u32(header, 16)? as usize / 8;
+
+    let verifier = &info[4 + header_size..];
+    let salt_size = read_u32(verifier, 0)? as usize;
+    let salt = read_bytes(verifier, 4, salt_size)?;
+    let encrypted_verifier = read_bytes(verifier, 4 + salt_size, 16)?;
+    let hash_size = read_u32(verifier, 20 + salt_size)? as usize;
+    let encrypted_hash = read_bytes(verifier, 24 + salt_size, 32)?;
+
+    let key = standard_key(salt, password, key_bytes);
+    let verifier_hash = HashAlgorithm::Sha1.digest(&[&aes_ecb_decrypt(&key, encrypted_verifier)?]);
+    let mut expected = aes_ecb_decrypt(&key, encrypted_hash)?;
+    expected.truncate(hash_size);
+    if verifier_hash != expected {
+        return Err(incorrect_password());
+    }
+
+    let (size, payload) = split_package(package)?;
+    let mut output = aes_ecb_decrypt(&key, payload)?;
+    output.truncate(size);
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test that encrypted fixtures are detected
+    #[test]
+    fn test_is_encrypted() {
+        let encrypted = std::fs::read("tests/fixtures/encrypted.xlsx").unwrap();
+        let plain = std::fs::read("tests/fixtures/test.xlsx").unwrap();
+
+        assert!(is_encrypted(&encrypted));
+        assert!(!is_encrypted(&plain));
+    }
+
+    /// TDD RED: Test decrypting an Agile encrypted workbook
+    #[test]
+    fn test_decrypt_agile() {
+        let data = std::fs::read("tests/fixtures/encrypted.xlsx").unwrap();
+
+        let decrypted = decrypt(&data, "secret").unwrap();
+
+        assert_eq!(
+            decrypted,
+            std::fs::read("tests/fixtures/test.xlsx").unwrap()
+        );
+    }
+
+    /// TDD RED: Test decrypting a Standard encrypted workbook
+    #[test]
+    fn test_decrypt_standard() {
+        let data = std::fs::read("tests/fixtures/encrypted_standard.xlsx").unwrap();
+
+        let decrypted = decrypt(&data, "secret").unwrap();
+
+        assert_eq!(
+            decrypted,
+            std::fs::read("tests/fixtures/test.xlsx").unwrap()
+        );
+    }
+
+    /// TDD RED: Test that a wrong password is reported clearly
+    #[test]
+    fn test_decrypt_wrong_password() {
+        for path in [
+            "tests/fixtures/encrypted.xlsx",
+            "tests/fixtures/encrypted_standard.xlsx",
+        ] {
+            let data = std::fs::read(path).unwrap();
+
+            let result = decrypt(&data, "wrong");
+
+            assert!(
+                matches!(&result, Err(Error::Other(msg)) if msg == "incorrect password"),
+                "Expected incorrect password for {path}, got {result:?}"
+            );
+        }
+    }
+
+    /// Build a compound file holding the given encryption streams
+    fn compound(info: &[u8], package: &[u8]) -> Vec<u8> {
+        use std::io::Write;
+
+        let mut compound = cfb::CompoundFile::create(Cursor::new(Vec::new())).unwrap();
+        compound
+            .create_stream("/EncryptionInfo")
+            .unwrap()
+            .write_all(info)
+            .unwrap();
+        compound
+            .create_stream("/EncryptedPackage")
+            .unwrap()
+            .write_all(package)
+            .unwrap();
+        compound.into_inner().into_inner()
+    }
+
+    /// TDD RED: Test that a truncated `EncryptionInfo` stream is rejected
+    #[test]
+    fn test_decrypt_truncated_info() {
+        // Arrange: Agile version header with only two of the flag bytes
+        let data = compound(&[4, 0, 4, 0, 0x40, 0], &[0; 16]);
+
+        // Act
+        let result = decrypt(&data, "secret");
+
+        // Assert: Reported as a bad file rather than panicking
+        assert!(matches!(result, Err(Error::InvalidFormat { .. })));
+    }
+
+    /// TDD RED: Test that an excessive spin count is refused before hashing
+    #[test]
+    fn test_decrypt_excessive_spin_count() {
+        // Arrange: The Agile fixture with its spin count raised far past the limit
+        let data = std::fs::read("tests/fixtures/encrypted.xlsx").unwrap();
+        let mut file = cfb::CompoundFile::open(Cursor::new(data.as_slice())).unwrap();
+        let info = read_stream(&mut file, "/EncryptionInfo").unwrap();
+        let package = read_stream(&mut file, "/EncryptedPackage").unwrap();
+        let (header, xml) = info.split_at(8);
+        let xml = String::from_utf8_lossy(xml)
+            .replace("spinCount=\"100000\"", "spinCount=\"4000000000\"");
+        let data = compound(&[header, xml.as_bytes()].concat(), &package);
+
+        // Act
+        let result = decrypt(&data, "secret");
+
+        // Assert
+        assert!(
+            matches!(&result, Err(Error::InvalidFormat { reason }) if reason.contains("spin count")),
+            "Expected spin count error, got {result:?}"
+        );
+    }
+
+    /// TDD RED: Test that a plain workbook is rejected
+    #[test]
+    fn test_decrypt_plain_file() {
+        let data = std::fs::read("tests/fixtures/test.xlsx").unwrap();
+
+        assert!(matches!(
+            decrypt(&data, "secret"),
+            Err(Error::InvalidFormat { .. })
+        ));
+    }
+}
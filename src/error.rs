@@ -47,6 +47,15 @@ pub enum Error {
         name: String,
     },
 
+    /// Sheet index past the last sheet
+    #[error("Sheet index {index} out of bounds, workbook has {count} sheets")]
+    SheetIndexOutOfBounds {
+        /// Zero-based index that was requested
+        index: usize,
+        /// Number of sheets in the workbook
+        count: usize,
+    },
+
     /// Cell reference error
     #[error("Invalid cell reference: {reference}")]
     InvalidCellReference {
@@ -61,6 +70,15 @@ pub enum Error {
         range: String,
     },
 
+    /// Write outside Excel's worksheet limits
+    #[error("Write limit exceeded: row {row}, column {col} is outside the worksheet")]
+    WriteLimitExceeded {
+        /// Zero-based row index that was written to
+        row: usize,
+        /// Zero-based column index that was written to
+        col: usize,
+    },
+
     /// Error from calamine (reading)
     #[error("Calamine error: {0}")]
     Calamine(#[from] calamine::Error),
@@ -93,6 +111,12 @@ impl Error {
         Self::SheetNotFound { name: name.into() }
     }
 
+    /// Create a new `SheetIndexOutOfBounds` error
+    #[must_use]
+    pub fn sheet_index_out_of_bounds(index: usize, count: usize) -> Self {
+        Self::SheetIndexOutOfBounds { index, count }
+    }
+
     /// Create a new `InvalidCellReference` error
     #[must_use]
     pub fn invalid_cell_reference(reference: impl Into<String>) -> Self {
@@ -101,6 +125,12 @@ impl Error {
         }
     }
 
+    /// Create a new `WriteLimitExceeded` error
+    #[must_use]
+    pub fn write_limit_exceeded(row: usize, col: usize) -> Self {
+        Self::WriteLimitExceeded { row, col }
+    }
+
     /// Create a new `InvalidRange` error
     #[must_use]
     pub fn invalid_range(range: impl Into<String>) -> Self {
@@ -127,6 +157,29 @@ mod tests {
         assert_eq!(err.to_string(), "Sheet not found: Sheet1");
     }
 
+    #[test]
+    fn test_sheet_index_out_of_bounds_error() {
+        let err = Error::sheet_index_out_of_bounds(3, 2);
+        assert!(matches!(
+            err,
+            Error::SheetIndexOutOfBounds { index: 3, count: 2 }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Sheet index 3 out of bounds, workbook has 2 sheets"
+        );
+    }
+
+    #[test]
+    fn test_write_limit_exceeded_error() {
+        let err = Error::write_limit_exceeded(1_048_577, 0);
+        assert!(matches!(err, Error::WriteLimitExceeded { .. }));
+        assert_eq!(
+            err.to_string(),
+            "Write limit exceeded: row 1048577, column 0 is outside the worksheet"
+        );
+    }
+
     #[test]
     fn test_invalid_cell_reference_error() {
         let err = Error::invalid_cell_reference("ZZZ999999");
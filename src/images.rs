@@ -0,0 +1,176 @@
+//! Embedded worksheet images
+//!
+//! Extracts pictures from a worksheet's drawing layer by following the
+//! workbook, worksheet and drawing relationships inside the xlsx package.
+
+use crate::error::{Error, Result};
+use crate::package::{element_attributes, Package};
+use quick_xml::events::Event;
+use quick_xml::Reader as XmlReader;
+use std::collections::HashMap;
+
+/// Image file format, taken from the media part's file extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    /// PNG image
+    Png,
+    /// JPEG image
+    Jpeg,
+    /// GIF image
+    Gif,
+    /// BMP image
+    Bmp,
+    /// TIFF image
+    Tiff,
+    /// Enhanced Metafile
+    Emf,
+    /// Windows Metafile
+    Wmf,
+    /// SVG image
+    Svg,
+    /// Unrecognized extension
+    Unknown,
+}
+
+impl ImageFormat {
+    /// Detect the format from a part name like `xl/media/image1.png`
+    fn from_path(path: &str) -> Self {
+        let extension = path.rsplit('.').next().unwrap_or_default();
+        match extension.to_ascii_lowercase().as_str() {
+            "png" => Self::Png,
+            "jpg" | "jpeg" => Self::Jpeg,
+            "gif" => Self::Gif,
+            "bmp" => Self::Bmp,
+            "tif" | "tiff" => Self::Tiff,
+            "emf" => Self::Emf,
+            "wmf" => Self::Wmf,
+            "svg" => Self::Svg,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+/// Image placed on a worksheet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddedImage {
+    /// Zero-based row of the cell holding the image's top-left corner
+    pub row: u32,
+    /// Zero-based column of the cell holding the image's top-left corner
+    pub col: u32,
+    /// Raw image bytes
+    pub data: Vec<u8>,
+    /// Image format
+    pub format: ImageFormat,
+}
+
+/// Picture anchor in a drawing part: top-left cell and image relationship ID
+struct Anchor {
+    row: u32,
+    col: u32,
+    embed: String,
+}
+
+/// Read the images on a worksheet from an xlsx package
+///
+/// # Errors
+///
+/// Returns `Error::SheetNotFound` if the sheet doesn't exist.
+/// Returns `Error::InvalidFormat` if the package or its parts are malformed.
+pub(crate) fn read_images(package: &mut Package, sheet: &str) -> Result<Vec<EmbeddedImage>> {
+    let sheet_part = package.sheet_part(sheet)?;
+    let Some(drawing_part) = package
+        .relationships(&sheet_part)?
+        .into_iter()
+        .find(|rel| rel.kind.ends_with("/drawing"))
+        .map(|rel| rel.target)
+    else {
+        return Ok(Vec::new());
+    };
+
+    let targets: HashMap<String, String> = package
+        .relationships(&drawing_part)?
+        .into_iter()
+        .map(|rel| (rel.id, rel.target))
+        .collect();
+    let drawing = package.require_text(&drawing_part)?;
+
+    parse_anchors(&drawing)?
+        .into_iter()
+        .filter_map(|anchor| targets.get(&anchor.embed).map(|target| (anchor, target)))
+        .map(|(anchor, target)| {
+            Ok(EmbeddedImage {
+                row: anchor.row,
+                col: anchor.col,
+                data: package
+                    .read_bytes(target)?
+                    .ok_or_else(|| Error::invalid_format(format!("missing part {target}")))?,
+                format: ImageFormat::from_path(target),
+            })
+        })
+        .collect()
+}
+
+/// Collect picture anchors from a drawing part
+fn parse_anchors(xml: &str) -> Result<Vec<Anchor>> {
+    let mut reader = XmlReader::from_str(xml);
+    let mut anchors = Vec::new();
+    let mut in_from = false;
+    let mut field: Option<&str> = None;
+    let (mut row, mut col, mut embed) = (0, 0, None);
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) => match e.local_name().as_ref() {
+                b"from" => in_from = true,
+                b"row" if in_from => field = Some("row"),
+                b"col" if in_from => field = Some("col"),
+                _ => {}
+            },
+            Ok(Event::Empty(e)) if e.local_name().as_ref() == b"blip" => {
+                embed = element_attributes(&e, &reader).remove("embed");
+            }
+            Ok(Event::Text(text)) => {
+                let value = text.unescape().ok().and_then(|t| t.trim().parse().ok());
+                match (field.take(), value) {
+                    (Some("row"), Some(value)) => row = value,
+                    (Some("col"), Some(value)) => col = value,
+                    _ => {}
+                }
+            }
+            Ok(Event::End(e)) => match e.local_name().as_ref() {
+                b"from" => in_from = false,
+                b"twoCellAnchor" | b"oneCellAnchor" => {
+                    if let Some(embed) = embed.take() {
+                        anchors.push(Anchor { row, col, embed });
+                    }
+                    (row, col) = (0, 0);
+                }
+                _ => field = None,
+            },
+            Ok(Event::Eof) => return Ok(anchors),
+            Err(e) => return Err(Error::invalid_format(format!("invalid drawing XML: {e}"))),
+            Ok(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test image format detection from extension
+    #[test]
+    fn test_image_format_from_path() {
+        assert_eq!(
+            ImageFormat::from_path("xl/media/image1.png"),
+            ImageFormat::Png
+        );
+        assert_eq!(
+            ImageFormat::from_path("xl/media/image2.JPEG"),
+            ImageFormat::Jpeg
+        );
+        assert_eq!(
+            ImageFormat::from_path("xl/media/image3.xyz"),
+            ImageFormat::Unknown
+        );
+    }
+}
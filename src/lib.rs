@@ -29,11 +29,25 @@
 #![warn(clippy::cognitive_complexity)]
 
 // Module declarations
+mod cell_styles;
 pub mod charts;
+mod comments;
 pub mod compat;
+pub mod conditional_format;
+pub mod encryption;
 pub mod error;
+pub mod images;
+mod number_formats;
+mod package;
+pub mod pivot_tables;
+pub mod properties;
 pub mod reader;
+mod repair;
+mod shared_formulas;
+pub mod sparkline;
 pub mod styles;
+pub mod table;
+pub mod textbox;
 pub mod validation;
 pub mod writer;
 
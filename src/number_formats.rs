@@ -0,0 +1,208 @@
+//! Cell number formats
+//!
+//! Resolves each cell's number format string from the worksheet's style
+//! indexes and the workbook's `xl/styles.xml`.
+
+use crate::compat::utils::coordinate_from_string;
+use crate::error::{Error, Result};
+use crate::package::{element_attributes, elements, Package};
+use quick_xml::events::Event;
+use quick_xml::Reader as XmlReader;
+use std::collections::HashMap;
+
+/// Number format of cells without an explicit format
+pub(crate) const GENERAL: &str = "General";
+
+/// Excel's built-in number formats by `numFmtId`
+const BUILTIN_FORMATS: [(u32, &str); 28] = [
+    (0, GENERAL),
+    (1, "0"),
+    (2, "0.00"),
+    (3, "#,##0"),
+    (4, "#,##0.00"),
+    (9, "0%"),
+    (10, "0.00%"),
+    (11, "0.00E+00"),
+    (12, "# ?/?"),
+    (13, "# ??/??"),
+    (14, "mm-dd-yy"),
+    (15, "d-mmm-yy"),
+    (16, "d-mmm"),
+    (17, "mmm-yy"),
+    (18, "h:mm AM/PM"),
+    (19, "h:mm:ss AM/PM"),
+    (20, "h:mm"),
+    (21, "h:mm:ss"),
+    (22, "m/d/yy h:mm"),
+    (37, "#,##0 ;(#,##0)"),
+    (38, "#,##0 ;[Red](#,##0)"),
+    (39, "#,##0.00;(#,##0.00)"),
+    (40, "#,##0.00;[Red](#,##0.00)"),
+    (45, "mm:ss"),
+    (46, "[h]:mm:ss"),
+    (47, "mmss.0"),
+    (48, "##0.0E+0"),
+    (49, "@"),
+];
+
+/// Read the number format of every formatted cell on a worksheet
+///
+/// Keys are zero-based `(row, col)`. Cells formatted as "General" are left
+/// out.
+///
+/// # Errors
+///
+/// Returns `Error::SheetNotFound` if the sheet doesn't exist.
+/// Returns `Error::InvalidFormat` if the package or its parts are malformed.
+pub(crate) fn read_number_formats(
+    package: &mut Package,
+    sheet: &str,
+) -> Result<HashMap<(u32, u32), String>> {
+    let sheet_part = package.sheet_part(sheet)?;
+    let formats = match package.read_text("xl/styles.xml")? {
+        Some(styles) => cell_xf_formats(&styles)?,
+        None => return Ok(HashMap::new()),
+    };
+    let worksheet = package.require_text(&sheet_part)?;
+
+    Ok(cell_style_indexes(&worksheet)?
+        .into_iter()
+        .filter_map(|(position, xf)| {
+            let format = formats.get(xf).filter(|format| *format != GENERAL)?;
+            Some((position, format.clone()))
+        })
+        .collect())
+}
+
+/// Zero-based `(row, col)` and cell style index of every styled cell
+///
+/// Only the `r` and `s` attributes of `<c>` elements are read.
+pub(crate) fn cell_style_indexes(worksheet: &str) -> Result<Vec<((u32, u32), usize)>> {
+    let mut reader = XmlReader::from_str(worksheet);
+    let mut indexes = Vec::new();
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e) | Event::Empty(e)) if e.local_name().as_ref() == b"c" => {
+                let (mut reference, mut xf) = (None, None);
+                for attr in e.attributes().flatten() {
+                    match attr.key.local_name().as_ref() {
+                        b"r" => reference = Some(attr.value),
+                        b"s" => xf = Some(attr.value),
+                        _ => {}
+                    }
+                }
+                if let (Some(reference), Some(xf)) = (reference, xf) {
+                    indexes.extend(style_index(&reference, &xf));
+                }
+            }
+            Ok(Event::Eof) => return Ok(indexes),
+            Err(e) => return Err(Error::invalid_format(format!("invalid worksheet XML: {e}"))),
+            Ok(_) => {}
+        }
+    }
+}
+
+/// Zero-based position and style index from a cell's `r` and `s` values
+fn style_index(reference: &[u8], xf: &[u8]) -> Option<((u32, u32), usize)> {
+    let xf = std::str::from_utf8(xf).ok()?.parse().ok()?;
+    let (row, col) = coordinate_from_string(std::str::from_utf8(reference).ok()?).ok()?;
+    let position = (u32::try_from(row - 1).ok()?, u32::try_from(col - 1).ok()?);
+    Some((position, xf))
+}
+
+/// Format string of each cell style (`cellXfs` entry), in index order
+fn cell_xf_formats(styles: &str) -> Result<Vec<String>> {
+    let custom = custom_formats(styles)?;
+
+    let mut reader = XmlReader::from_str(styles);
+    let mut in_cell_xfs = false;
+    let mut formats = Vec::new();
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e) | Event::Empty(e)) => match e.local_name().as_ref() {
+                b"cellXfs" => in_cell_xfs = true,
+                b"xf" if in_cell_xfs => {
+                    let id = element_attributes(&e, &reader)
+                        .get("numFmtId")
+                        .and_then(|id| id.parse().ok())
+                        .unwrap_or(0);
+                    formats.push(format_code(id, &custom));
+                }
+                _ => {}
+            },
+            Ok(Event::End(e)) if e.local_name().as_ref() == b"cellXfs" => in_cell_xfs = false,
+            Ok(Event::Eof) => return Ok(formats),
+            Err(e) => return Err(Error::invalid_format(format!("invalid styles XML: {e}"))),
+            Ok(_) => {}
+        }
+    }
+}
+
+/// Custom format strings by `numFmtId` from `xl/styles.xml`
+pub(crate) fn custom_formats(styles: &str) -> Result<HashMap<u32, String>> {
+    Ok(elements(styles)?
+        .into_iter()
+        .filter(|(name, _)| name == "numFmt")
+        .filter_map(|(_, mut attrs)| {
+            Some((
+                attrs.get("numFmtId")?.parse().ok()?,
+                attrs.remove("formatCode")?,
+            ))
+        })
+        .collect())
+}
+
+/// Format string for a `numFmtId`, custom formats taking precedence
+pub(crate) fn format_code(id: u32, custom: &HashMap<u32, String>) -> String {
+    custom
+        .get(&id)
+        .map(String::as_str)
+        .or_else(|| {
+            BUILTIN_FORMATS
+                .iter()
+                .find(|(builtin, _)| *builtin == id)
+                .map(|(_, code)| *code)
+        })
+        .unwrap_or(GENERAL)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test cell style formats resolve built-in and custom IDs
+    #[test]
+    fn test_cell_xf_formats() {
+        let styles = r#"<styleSheet>
+            <numFmts count="1"><numFmt numFmtId="164" formatCode="&quot;$&quot;#,##0.00"/></numFmts>
+            <cellStyleXfs count="1"><xf numFmtId="4"/></cellStyleXfs>
+            <cellXfs count="4">
+                <xf numFmtId="0"/>
+                <xf numFmtId="164" applyNumberFormat="1"/>
+                <xf numFmtId="14" applyNumberFormat="1"/>
+                <xf numFmtId="200"/>
+            </cellXfs>
+        </styleSheet>"#;
+
+        let formats = cell_xf_formats(styles).unwrap();
+
+        assert_eq!(
+            formats,
+            vec!["General", "\"$\"#,##0.00", "mm-dd-yy", "General"]
+        );
+    }
+
+    /// TDD RED: Test style indexes are read only from styled cells
+    #[test]
+    fn test_cell_style_indexes() {
+        let worksheet = r#"<worksheet><sheetData>
+            <row r="1" s="5" customFormat="1"><c r="A1"><v>1</v></c><c r="B1" s="2"><v>2</v></c></row>
+            <row r="3"><c r="C3" t="s" s="7"/></row>
+        </sheetData></worksheet>"#;
+
+        let indexes = cell_style_indexes(worksheet).unwrap();
+
+        assert_eq!(indexes, vec![((0, 1), 2), ((2, 2), 7)]);
+    }
+}
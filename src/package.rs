@@ -0,0 +1,303 @@
+//! Raw xlsx package access
+//!
+//! Reads parts of the xlsx zip package that calamine does not expose, such
+//! as drawings and cell styles, by following package relationships.
+
+use crate::error::{Error, Result};
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader as XmlReader;
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Cursor, Read, Seek, Write};
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipArchive, ZipWriter};
+
+/// Seekable byte source for a package, either a file or a memory buffer
+pub(crate) trait ReadSeek: Read + Seek {}
+
+impl<T: Read + Seek> ReadSeek for T {}
+
+/// Package relationship with its target resolved to a part name, or kept
+/// as written for external targets such as URLs and file paths
+pub(crate) struct Relationship {
+    pub(crate) id: String,
+    pub(crate) kind: String,
+    pub(crate) target: String,
+}
+
+/// Opened xlsx package
+pub(crate) struct Package {
+    archive: ZipArchive<Box<dyn ReadSeek>>,
+}
+
+impl Package {
+    /// Open a package from its bytes
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidFormat` if the data is not a zip package.
+    pub(crate) fn open(reader: Box<dyn ReadSeek>) -> Result<Self> {
+        let archive = ZipArchive::new(reader)
+            .map_err(|e| Error::invalid_format(format!("not an xlsx package: {e}")))?;
+        Ok(Self { archive })
+    }
+
+    /// Find the worksheet part for a sheet name via `xl/workbook.xml`
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::SheetNotFound` if the sheet doesn't exist.
+    pub(crate) fn sheet_part(&mut self, sheet: &str) -> Result<String> {
+        let workbook = self.require_text("xl/workbook.xml")?;
+        let id = elements(&workbook)?
+            .into_iter()
+            .find(|(name, attrs)| {
+                name == "sheet" && attrs.get("name").map(String::as_str) == Some(sheet)
+            })
+            .and_then(|(_, mut attrs)| attrs.remove("id"))
+            .ok_or_else(|| Error::sheet_not_found(sheet))?;
+        self.relationships("xl/workbook.xml")?
+            .into_iter()
+            .find(|rel| rel.id == id)
+            .map(|rel| rel.target)
+            .ok_or_else(|| Error::invalid_format(format!("missing relationship {id}")))
+    }
+
+    /// Map every sheet name to its part via `xl/workbook.xml`
+    ///
+    /// Sheets whose relationship is missing are left out.
+    pub(crate) fn sheet_parts(&mut self) -> Result<HashMap<String, String>> {
+        let workbook = self.require_text("xl/workbook.xml")?;
+        let targets: HashMap<String, String> = self
+            .relationships("xl/workbook.xml")?
+            .into_iter()
+            .map(|rel| (rel.id, rel.target))
+            .collect();
+        Ok(elements(&workbook)?
+            .into_iter()
+            .filter(|(name, _)| name == "sheet")
+            .filter_map(|(_, mut attrs)| {
+                let target = targets.get(&attrs.remove("id")?)?;
+                Some((attrs.remove("name")?, target.clone()))
+            })
+            .collect())
+    }
+
+    /// Read the relationships of a part, or none if it has no `.rels` part
+    pub(crate) fn relationships(&mut self, part: &str) -> Result<Vec<Relationship>> {
+        let (dir, file) = part.rsplit_once('/').unwrap_or(("", part));
+        let Some(xml) = self.read_text(&format!("{dir}/_rels/{file}.rels"))? else {
+            return Ok(Vec::new());
+        };
+        Ok(elements(&xml)?
+            .into_iter()
+            .filter(|(name, _)| name == "Relationship")
+            .filter_map(|(_, mut attrs)| {
+                let target = attrs.remove("Target")?;
+                let external = attrs.get("TargetMode").map(String::as_str) == Some("External");
+                Some(Relationship {
+                    id: attrs.remove("Id")?,
+                    kind: attrs.remove("Type")?,
+                    target: if external {
+                        target
+                    } else {
+                        resolve_target(dir, &target)
+                    },
+                })
+            })
+            .collect())
+    }
+
+    /// Names of all parts in the package
+    pub(crate) fn part_names(&self) -> Vec<String> {
+        self.archive.file_names().map(str::to_string).collect()
+    }
+
+    /// Read a part's bytes, or `None` if the part doesn't exist
+    pub(crate) fn read_bytes(&mut self, part: &str) -> Result<Option<Vec<u8>>> {
+        let mut file = match self.archive.by_name(part) {
+            Ok(file) => file,
+            Err(zip::result::ZipError::FileNotFound) => return Ok(None),
+            Err(e) => return Err(Error::invalid_format(format!("cannot read {part}: {e}"))),
+        };
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)?;
+        Ok(Some(data))
+    }
+
+    /// Stream a part without reading it into memory, or `None` if the part
+    /// doesn't exist
+    pub(crate) fn part_reader(&mut self, part: &str) -> Result<Option<impl Read + '_>> {
+        match self.archive.by_name(part) {
+            Ok(file) => Ok(Some(file)),
+            Err(zip::result::ZipError::FileNotFound) => Ok(None),
+            Err(e) => Err(Error::invalid_format(format!("cannot read {part}: {e}"))),
+        }
+    }
+
+    /// Read a part as UTF-8 text, or `None` if the part doesn't exist
+    pub(crate) fn read_text(&mut self, part: &str) -> Result<Option<String>> {
+        self.read_bytes(part)?
+            .map(|data| {
+                String::from_utf8(data)
+                    .map_err(|_| Error::invalid_format(format!("{part} is not valid UTF-8")))
+            })
+            .transpose()
+    }
+
+    /// Read a part as UTF-8 text that must exist
+    ///
+    /// # Errors
+    ///
+    /// Returns `Error::InvalidFormat` if the part is missing.
+    pub(crate) fn require_text(&mut self, part: &str) -> Result<String> {
+        self.read_text(part)?
+            .ok_or_else(|| Error::invalid_format(format!("missing part {part}")))
+    }
+}
+
+/// Copy a package, replacing the contents of existing parts in `parts` and
+/// adding the rest as new parts
+///
+/// # Errors
+///
+/// Returns `Error::InvalidFormat` if the package can't be rewritten.
+pub(crate) fn rewrite_package(package: &[u8], parts: &BTreeMap<String, String>) -> Result<Vec<u8>> {
+    let mut archive = ZipArchive::new(Cursor::new(package)).map_err(zip_error)?;
+    let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let mut written = Vec::new();
+    for index in 0..archive.len() {
+        let file = archive.by_index(index).map_err(zip_error)?;
+        let Some((name, xml)) = parts.get_key_value(file.name()) else {
+            writer.raw_copy_file(file).map_err(zip_error)?;
+            continue;
+        };
+        writer
+            .start_file(name.as_str(), options)
+            .map_err(zip_error)?;
+        writer.write_all(xml.as_bytes())?;
+        written.push(name);
+    }
+    for (name, xml) in parts.iter().filter(|(name, _)| !written.contains(name)) {
+        writer
+            .start_file(name.as_str(), options)
+            .map_err(zip_error)?;
+        writer.write_all(xml.as_bytes())?;
+    }
+
+    Ok(writer.finish().map_err(zip_error)?.into_inner())
+}
+
+/// Wrap a zip error as an invalid package
+#[allow(clippy::needless_pass_by_value)] // Used with `map_err`
+fn zip_error(error: zip::result::ZipError) -> Error {
+    Error::invalid_format(format!("cannot rewrite xlsx package: {error}"))
+}
+
+/// Resolve a relationship target relative to the source part's directory
+fn resolve_target(dir: &str, target: &str) -> String {
+    if let Some(absolute) = target.strip_prefix('/') {
+        return absolute.to_string();
+    }
+    let mut segments: Vec<&str> = dir.split('/').filter(|s| !s.is_empty()).collect();
+    for segment in target.split('/') {
+        match segment {
+            ".." => {
+                segments.pop();
+            }
+            "." | "" => {}
+            _ => segments.push(segment),
+        }
+    }
+    segments.join("/")
+}
+
+/// Collect every element's local name and attributes, in document order
+pub(crate) fn elements(xml: &str) -> Result<Vec<(String, HashMap<String, String>)>> {
+    let mut reader = XmlReader::from_str(xml);
+    let mut elements = Vec::new();
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e) | Event::Empty(e)) => {
+                let name = String::from_utf8_lossy(e.local_name().as_ref()).into_owned();
+                elements.push((name, element_attributes(&e, &reader)));
+            }
+            Ok(Event::Eof) => return Ok(elements),
+            Err(e) => return Err(Error::invalid_format(format!("invalid package XML: {e}"))),
+            Ok(_) => {}
+        }
+    }
+}
+
+/// Attributes and concatenated text content of every `name` element
+pub(crate) fn text_elements(
+    xml: &str,
+    name: &[u8],
+) -> Result<Vec<(HashMap<String, String>, String)>> {
+    let mut reader = XmlReader::from_str(xml);
+    let mut found = Vec::new();
+    let mut current: Option<(HashMap<String, String>, String)> = None;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) if e.local_name().as_ref() == name => {
+                current = Some((element_attributes(&e, &reader), String::new()));
+            }
+            Ok(Event::Empty(e)) if e.local_name().as_ref() == name => {
+                found.push((element_attributes(&e, &reader), String::new()));
+            }
+            Ok(Event::Text(text)) => {
+                if let Some((_, content)) = current.as_mut() {
+                    let text = text
+                        .unescape()
+                        .map_err(|e| Error::invalid_format(format!("invalid package XML: {e}")))?;
+                    content.push_str(&text);
+                }
+            }
+            Ok(Event::End(e)) if e.local_name().as_ref() == name => {
+                found.extend(current.take());
+            }
+            Ok(Event::Eof) => return Ok(found),
+            Err(e) => return Err(Error::invalid_format(format!("invalid package XML: {e}"))),
+            Ok(_) => {}
+        }
+    }
+}
+
+/// Attributes of an element, keyed by local name
+pub(crate) fn element_attributes<R>(
+    element: &BytesStart<'_>,
+    reader: &XmlReader<R>,
+) -> HashMap<String, String> {
+    element
+        .attributes()
+        .flatten()
+        .filter_map(|a| {
+            let key = String::from_utf8(a.key.local_name().as_ref().to_vec()).ok()?;
+            let value = a.decode_and_unescape_value(reader).ok()?.into_owned();
+            Some((key, value))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test relationship targets resolve against the source part
+    #[test]
+    fn test_resolve_target() {
+        assert_eq!(
+            resolve_target("xl/worksheets", "../drawings/drawing1.xml"),
+            "xl/drawings/drawing1.xml"
+        );
+        assert_eq!(
+            resolve_target("xl", "worksheets/sheet1.xml"),
+            "xl/worksheets/sheet1.xml"
+        );
+        assert_eq!(
+            resolve_target("xl", "/xl/media/image1.png"),
+            "xl/media/image1.png"
+        );
+    }
+}
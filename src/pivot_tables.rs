@@ -0,0 +1,150 @@
+//! Pivot table definitions
+//!
+//! Lists the pivot tables of an xlsx workbook with their source data and
+//! fields, by following each worksheet's pivot table relationships to the
+//! pivot table and pivot cache definition parts. Pivot tables are read
+//! only; their cached values are not evaluated.
+
+use crate::error::Result;
+use crate::package::{elements, Package};
+use std::collections::HashMap;
+
+/// Pivot table found in a workbook
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PivotTableInfo {
+    /// Pivot table name as shown in Excel
+    pub name: String,
+    /// Name of the worksheet holding the pivot table
+    pub sheet: String,
+    /// Cells the pivot table occupies, e.g. "A3:B6"
+    pub location: String,
+    /// Source data as a sheet range like "Data!A1:C5", or the defined name
+    /// or table it reads from; `None` for external or consolidated sources
+    pub source: Option<String>,
+    /// Source field names in column order
+    pub fields: Vec<String>,
+}
+
+/// Read every pivot table in an xlsx package, in sheet order
+///
+/// # Errors
+///
+/// Returns `Error::InvalidFormat` if the package or its parts are malformed.
+pub(crate) fn read_pivot_tables(package: &mut Package) -> Result<Vec<PivotTableInfo>> {
+    let workbook = package.require_text("xl/workbook.xml")?;
+    let sheet_names: Vec<String> = elements(&workbook)?
+        .into_iter()
+        .filter(|(name, _)| name == "sheet")
+        .filter_map(|(_, mut attrs)| attrs.remove("name"))
+        .collect();
+    let sheet_parts = package.sheet_parts()?;
+
+    let mut pivot_tables = Vec::new();
+    for sheet in sheet_names {
+        let Some(sheet_part) = sheet_parts.get(&sheet) else {
+            continue;
+        };
+        let pivot_parts: Vec<String> = package
+            .relationships(sheet_part)?
+            .into_iter()
+            .filter(|rel| rel.kind.ends_with("/pivotTable"))
+            .map(|rel| rel.target)
+            .collect();
+        for pivot_part in pivot_parts {
+            let definition = attributes_by_element(&package.require_text(&pivot_part)?)?;
+            let cache_part = package
+                .relationships(&pivot_part)?
+                .into_iter()
+                .find(|rel| rel.kind.ends_with("/pivotCacheDefinition"))
+                .map(|rel| rel.target);
+            let (source, fields) = match cache_part {
+                Some(cache_part) => read_cache_definition(&package.require_text(&cache_part)?)?,
+                None => (None, Vec::new()),
+            };
+            let attribute = |element: &str, key: &str| {
+                definition
+                    .get(element)
+                    .and_then(|attrs| attrs.get(key))
+                    .cloned()
+                    .unwrap_or_default()
+            };
+            pivot_tables.push(PivotTableInfo {
+                name: attribute("pivotTableDefinition", "name"),
+                sheet: sheet.clone(),
+                location: attribute("location", "ref"),
+                source,
+                fields,
+            });
+        }
+    }
+    Ok(pivot_tables)
+}
+
+/// Attributes of the first element of each name in a part
+fn attributes_by_element(xml: &str) -> Result<HashMap<String, HashMap<String, String>>> {
+    let mut first = HashMap::new();
+    for (name, attrs) in elements(xml)? {
+        first.entry(name).or_insert(attrs);
+    }
+    Ok(first)
+}
+
+/// Source and field names from a pivot cache definition part
+fn read_cache_definition(xml: &str) -> Result<(Option<String>, Vec<String>)> {
+    let mut source = None;
+    let mut fields = Vec::new();
+    for (name, mut attrs) in elements(xml)? {
+        match name.as_str() {
+            "worksheetSource" => {
+                source = match (
+                    attrs.remove("sheet"),
+                    attrs.remove("ref"),
+                    attrs.remove("name"),
+                ) {
+                    (Some(sheet), Some(range), _) => {
+                        Some(format!("{}!{range}", sheet_prefix(&sheet)))
+                    }
+                    (_, _, Some(name)) => Some(name),
+                    (None, Some(range), None) => Some(range),
+                    _ => None,
+                };
+            }
+            "cacheField" => fields.extend(attrs.remove("name")),
+            _ => {}
+        }
+    }
+    Ok((source, fields))
+}
+
+/// Sheet name as written before `!` in a reference, quoted when needed
+fn sheet_prefix(sheet: &str) -> String {
+    if sheet.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        sheet.to_string()
+    } else {
+        format!("'{}'", sheet.replace('\'', "''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test reading a named source and quoting sheet names
+    #[test]
+    fn test_read_cache_definition() {
+        let xml = r#"<pivotCacheDefinition><cacheSource type="worksheet"><worksheetSource ref="A1:B9" sheet="Q1 Sales"/></cacheSource><cacheFields count="2"><cacheField name="Rep"/><cacheField name="Total"/></cacheFields></pivotCacheDefinition>"#;
+        assert_eq!(
+            read_cache_definition(xml).unwrap(),
+            (
+                Some("'Q1 Sales'!A1:B9".to_string()),
+                vec!["Rep".to_string(), "Total".to_string()]
+            )
+        );
+
+        let xml = r#"<pivotCacheDefinition><cacheSource type="worksheet"><worksheetSource name="SalesTable"/></cacheSource></pivotCacheDefinition>"#;
+        assert_eq!(
+            read_cache_definition(xml).unwrap(),
+            (Some("SalesTable".to_string()), Vec::new())
+        );
+    }
+}
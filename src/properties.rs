@@ -0,0 +1,403 @@
+//! Document properties
+//!
+//! Provides the workbook metadata Excel shows under File > Info, such as
+//! title and author, plus user-defined custom properties.
+
+use crate::error::{Error, Result};
+use crate::package::{element_attributes, text_elements, Package};
+use quick_xml::events::Event;
+use quick_xml::Reader as XmlReader;
+
+/// Value of a custom document property
+#[derive(Debug, Clone, PartialEq)]
+pub enum PropertyValue {
+    /// Text value
+    Text(String),
+    /// Whole number value
+    Integer(i32),
+    /// Floating point value
+    Number(f64),
+    /// Yes/No value
+    Boolean(bool),
+}
+
+impl From<&str> for PropertyValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for PropertyValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<i32> for PropertyValue {
+    fn from(value: i32) -> Self {
+        Self::Integer(value)
+    }
+}
+
+impl From<f64> for PropertyValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<bool> for PropertyValue {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+/// Workbook document properties
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use xlsxpress::properties::DocProperties;
+///
+/// let props = DocProperties::new()
+///     .title("Quarterly Report")
+///     .author("Finance Team")
+///     .custom_property("Reviewed", true);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DocProperties {
+    /// Document title
+    title: Option<String>,
+    /// Document subject
+    subject: Option<String>,
+    /// Document author
+    author: Option<String>,
+    /// Author's company
+    company: Option<String>,
+    /// Search keywords
+    keywords: Option<String>,
+    /// Free-form comments
+    comments: Option<String>,
+    /// User-defined properties, in insertion order
+    custom: Vec<(String, PropertyValue)>,
+}
+
+impl DocProperties {
+    /// Create empty document properties
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the document title
+    #[must_use]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the document subject
+    #[must_use]
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.subject = Some(subject.into());
+        self
+    }
+
+    /// Set the document author
+    #[must_use]
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// Set the author's company
+    #[must_use]
+    pub fn company(mut self, company: impl Into<String>) -> Self {
+        self.company = Some(company.into());
+        self
+    }
+
+    /// Set search keywords, e.g. `"sales, q3, forecast"`
+    #[must_use]
+    pub fn keywords(mut self, keywords: impl Into<String>) -> Self {
+        self.keywords = Some(keywords.into());
+        self
+    }
+
+    /// Set free-form comments
+    #[must_use]
+    pub fn comments(mut self, comments: impl Into<String>) -> Self {
+        self.comments = Some(comments.into());
+        self
+    }
+
+    /// Add a custom property, replacing any existing one with the same name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Property name
+    /// * `value` - Text, whole number, floating point or boolean value
+    #[must_use]
+    pub fn custom_property(
+        mut self,
+        name: impl Into<String>,
+        value: impl Into<PropertyValue>,
+    ) -> Self {
+        let name = name.into();
+        let value = value.into();
+        match self
+            .custom
+            .iter_mut()
+            .find(|(existing, _)| *existing == name)
+        {
+            Some((_, existing)) => *existing = value,
+            None => self.custom.push((name, value)),
+        }
+        self
+    }
+
+    /// Get the document title
+    #[must_use]
+    pub fn get_title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Get the document subject
+    #[must_use]
+    pub fn get_subject(&self) -> Option<&str> {
+        self.subject.as_deref()
+    }
+
+    /// Get the document author
+    #[must_use]
+    pub fn get_author(&self) -> Option<&str> {
+        self.author.as_deref()
+    }
+
+    /// Get the author's company
+    #[must_use]
+    pub fn get_company(&self) -> Option<&str> {
+        self.company.as_deref()
+    }
+
+    /// Get the search keywords
+    #[must_use]
+    pub fn get_keywords(&self) -> Option<&str> {
+        self.keywords.as_deref()
+    }
+
+    /// Get the comments
+    #[must_use]
+    pub fn get_comments(&self) -> Option<&str> {
+        self.comments.as_deref()
+    }
+
+    /// Get a custom property by name
+    #[must_use]
+    pub fn get_custom_property(&self, name: &str) -> Option<&PropertyValue> {
+        self.custom
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, value)| value)
+    }
+
+    /// Get all custom properties, in insertion order
+    #[must_use]
+    pub fn get_custom_properties(&self) -> &[(String, PropertyValue)] {
+        &self.custom
+    }
+
+    /// Convert to `rust_xlsxwriter` document properties
+    pub(crate) fn into_xlsx_properties(self) -> rust_xlsxwriter::DocProperties {
+        let mut props = rust_xlsxwriter::DocProperties::new();
+        if let Some(title) = self.title {
+            props = props.set_title(title);
+        }
+        if let Some(subject) = self.subject {
+            props = props.set_subject(subject);
+        }
+        if let Some(author) = self.author {
+            props = props.set_author(author);
+        }
+        if let Some(company) = self.company {
+            props = props.set_company(company);
+        }
+        if let Some(keywords) = self.keywords {
+            props = props.set_keywords(keywords);
+        }
+        if let Some(comments) = self.comments {
+            props = props.set_comment(comments);
+        }
+        for (name, value) in self.custom {
+            props = match value {
+                PropertyValue::Text(text) => props.set_custom_property(name, text),
+                PropertyValue::Integer(number) => props.set_custom_property(name, number),
+                PropertyValue::Number(number) => props.set_custom_property(name, number),
+                PropertyValue::Boolean(boolean) => props.set_custom_property(name, boolean),
+            };
+        }
+        props
+    }
+}
+
+/// Read the document properties of an xlsx package
+///
+/// Parts that are missing leave their properties unset.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidFormat` if a property part is malformed.
+pub(crate) fn read_properties(package: &mut Package) -> Result<DocProperties> {
+    let mut props = DocProperties::new();
+    if let Some(core) = package.read_text("docProps/core.xml")? {
+        props.title = first_text(&core, b"title")?;
+        props.subject = first_text(&core, b"subject")?;
+        props.author = first_text(&core, b"creator")?;
+        props.keywords = first_text(&core, b"keywords")?;
+        props.comments = first_text(&core, b"description")?;
+    }
+    if let Some(app) = package.read_text("docProps/app.xml")? {
+        props.company = first_text(&app, b"Company")?;
+    }
+    if let Some(custom) = package.read_text("docProps/custom.xml")? {
+        props.custom = custom_properties(&custom)?;
+    }
+    Ok(props)
+}
+
+/// Text of the first `name` element, if present and not empty
+fn first_text(xml: &str, name: &[u8]) -> Result<Option<String>> {
+    Ok(text_elements(xml, name)?
+        .into_iter()
+        .map(|(_, text)| text)
+        .find(|text| !text.is_empty()))
+}
+
+/// Custom properties from `docProps/custom.xml`, in document order
+fn custom_properties(xml: &str) -> Result<Vec<(String, PropertyValue)>> {
+    let mut reader = XmlReader::from_str(xml);
+    let mut properties = Vec::new();
+    let mut name: Option<String> = None;
+    // `vt:` element being read and its text so far
+    let mut value: Option<(Vec<u8>, String)> = None;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) if e.local_name().as_ref() == b"property" => {
+                name = element_attributes(&e, &reader).remove("name");
+            }
+            Ok(Event::Start(e)) if name.is_some() => {
+                value = Some((e.local_name().as_ref().to_vec(), String::new()));
+            }
+            Ok(Event::Empty(e)) if e.local_name().as_ref() != b"property" => {
+                if let Some(name) = name.take() {
+                    properties.push((name, property_value(e.local_name().as_ref(), "")));
+                }
+            }
+            Ok(Event::Text(text)) => {
+                if let Some((_, value)) = value.as_mut() {
+                    let text = text
+                        .unescape()
+                        .map_err(|e| Error::invalid_format(format!("invalid custom.xml: {e}")))?;
+                    value.push_str(&text);
+                }
+            }
+            Ok(Event::End(e)) if e.local_name().as_ref() == b"property" => name = None,
+            Ok(Event::End(_)) => {
+                if let (Some(name), Some((kind, text))) = (name.take(), value.take()) {
+                    properties.push((name, property_value(&kind, &text)));
+                }
+            }
+            Ok(Event::Eof) => return Ok(properties),
+            Err(e) => return Err(Error::invalid_format(format!("invalid custom.xml: {e}"))),
+            Ok(_) => {}
+        }
+    }
+}
+
+/// Typed value of a custom property from its `vt:` element name and text
+fn property_value(kind: &[u8], text: &str) -> PropertyValue {
+    let value = match kind {
+        b"i4" => text.parse().ok().map(PropertyValue::Integer),
+        b"r8" => text.parse().ok().map(PropertyValue::Number),
+        b"bool" => Some(PropertyValue::Boolean(text == "true" || text == "1")),
+        _ => None,
+    };
+    value.unwrap_or_else(|| PropertyValue::Text(text.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test properties builder and custom property replacement
+    #[test]
+    fn test_doc_properties_builder() {
+        let props = DocProperties::new()
+            .title("Report")
+            .company("Acme")
+            .custom_property("Reviewed", false)
+            .custom_property("Reviewed", true)
+            .custom_property("Version", 3);
+
+        assert_eq!(props.get_title(), Some("Report"));
+        assert_eq!(props.get_company(), Some("Acme"));
+        assert_eq!(props.get_author(), None);
+        assert_eq!(
+            props.get_custom_property("Reviewed"),
+            Some(&PropertyValue::Boolean(true))
+        );
+        assert_eq!(props.get_custom_properties().len(), 2);
+    }
+
+    /// TDD RED: Test custom properties keep their value types
+    #[test]
+    fn test_custom_properties() {
+        let xml = r#"<Properties xmlns:vt="vt">
+            <property fmtid="x" pid="2" name="Owner"><vt:lpwstr>Ops &amp; IT</vt:lpwstr></property>
+            <property fmtid="x" pid="3" name="Version"><vt:i4>3</vt:i4></property>
+            <property fmtid="x" pid="4" name="Rate"><vt:r8>1.5</vt:r8></property>
+            <property fmtid="x" pid="5" name="Final"><vt:bool>true</vt:bool></property>
+        </Properties>"#;
+
+        let properties = custom_properties(xml).unwrap();
+
+        assert_eq!(
+            properties,
+            vec![
+                (
+                    "Owner".to_string(),
+                    PropertyValue::Text("Ops & IT".to_string())
+                ),
+                ("Version".to_string(), PropertyValue::Integer(3)),
+                ("Rate".to_string(), PropertyValue::Number(1.5)),
+                ("Final".to_string(), PropertyValue::Boolean(true)),
+            ]
+        );
+    }
+
+    /// TDD RED: Test pretty-printed custom properties and empty text values
+    #[test]
+    fn test_custom_properties_indented() {
+        let xml = r#"<Properties xmlns:vt="vt">
+            <property fmtid="x" pid="2" name="Owner">
+                <vt:lpwstr>Ops</vt:lpwstr>
+            </property>
+            <property fmtid="x" pid="3" name="Notes">
+                <vt:lpwstr/>
+            </property>
+            <property fmtid="x" pid="4" name="Version">
+                <vt:i4>3</vt:i4>
+            </property>
+        </Properties>"#;
+
+        let properties = custom_properties(xml).unwrap();
+
+        assert_eq!(
+            properties,
+            vec![
+                ("Owner".to_string(), PropertyValue::Text("Ops".to_string())),
+                ("Notes".to_string(), PropertyValue::Text(String::new())),
+                ("Version".to_string(), PropertyValue::Integer(3)),
+            ]
+        );
+    }
+}
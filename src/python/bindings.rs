@@ -1,15 +1,28 @@
 //! Core Python bindings for Writer and Reader
 
+use crate::python::charts::{
+    PyAreaChart, PyBarChart, PyColumnChart, PyDoughnutChart, PyLineChart, PyPieChart,
+    PyScatterChart, PyStockChart,
+};
+use crate::python::styles::PyStyle;
+use crate::python::validation::PyDataValidation;
 use crate::writer::Writer;
-use calamine::DataType;
+use calamine::{Data, DataType};
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*; // For is_empty() method
+use pyo3::types::PyDict;
+use pyo3::IntoPyObjectExt;
 
 /// Python wrapper for Writer
+///
+/// Can be used as a context manager. On a clean exit the workbook is saved to
+/// the path given to `set_path()`; if no path was set and `save()` was not
+/// called, exiting raises `ValueError`. Nothing is saved if the block raised.
 #[pyclass(name = "Writer", unsendable)]
 pub struct PyWriter {
     inner: Option<Writer>, // Option to allow taking ownership for save()
     sheet_count: usize,    // Track number of sheets
+    path: Option<String>,  // Save target used by the context manager
 }
 
 #[pymethods]
@@ -20,9 +33,41 @@ impl PyWriter {
         Self {
             inner: Some(Writer::new()),
             sheet_count: 0,
+            path: None,
         }
     }
 
+    /// Set the path used to save the workbook when leaving a `with` block
+    fn set_path(&mut self, path: &str) {
+        self.path = Some(path.to_string());
+    }
+
+    /// Enter the context manager
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Exit the context manager, saving to the configured path
+    ///
+    /// Does not suppress exceptions raised inside the `with` block.
+    #[pyo3(signature = (exc_type, _exc_value, _traceback))]
+    fn __exit__(
+        &mut self,
+        exc_type: Option<&Bound<'_, PyAny>>,
+        _exc_value: Option<&Bound<'_, PyAny>>,
+        _traceback: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<bool> {
+        if exc_type.is_some() || self.inner.is_none() {
+            return Ok(false);
+        }
+
+        let path = self.path.clone().ok_or_else(|| {
+            PyValueError::new_err("Writer was not saved; call set_path() or save() before exit")
+        })?;
+        self.save(&path)?;
+        Ok(false)
+    }
+
     /// Add a worksheet
     fn add_worksheet(&mut self, name: &str) -> PyResult<usize> {
         let writer = self
@@ -30,9 +75,7 @@ impl PyWriter {
             .as_mut()
             .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
 
-        writer
-            .add_worksheet(name)
-            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        writer.add_worksheet(name)?;
 
         let sheet_index = self.sheet_count;
         self.sheet_count += 1;
@@ -46,9 +89,8 @@ impl PyWriter {
             .as_mut()
             .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
 
-        writer
-            .write_string(sheet, row, col, value)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+        writer.write_string(sheet, row, col, value)?;
+        Ok(())
     }
 
     /// Write a number to a cell
@@ -58,9 +100,8 @@ impl PyWriter {
             .as_mut()
             .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
 
-        writer
-            .write_number(sheet, row, col, value)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+        writer.write_number(sheet, row, col, value)?;
+        Ok(())
     }
 
     /// Write a boolean to a cell
@@ -70,9 +111,8 @@ impl PyWriter {
             .as_mut()
             .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
 
-        writer
-            .write_boolean(sheet, row, col, value)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+        writer.write_boolean(sheet, row, col, value)?;
+        Ok(())
     }
 
     /// Write a formula to a cell
@@ -88,9 +128,8 @@ impl PyWriter {
             .as_mut()
             .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
 
-        writer
-            .write_formula(sheet, row, col, formula)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+        writer.write_formula(sheet, row, col, formula)?;
+        Ok(())
     }
 
     /// Write a URL to a cell
@@ -100,53 +139,159 @@ impl PyWriter {
             .as_mut()
             .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
 
-        writer
-            .write_url(sheet, row, col, url)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
-    }
-
-    // TODO: Re-enable styled write methods after fixing styles module
-    // /// Write a string with style
-    // fn write_string_with_style(
-    //     &mut self,
-    //     sheet: usize,
-    //     row: usize,
-    //     col: usize,
-    //     value: &str,
-    //     style: &PyStyle,
-    // ) -> PyResult<()> {
-    //     let writer = self.inner.as_mut()
-    //         .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
-    //
-    //     writer.write_string_with_style(sheet, row, col, value, &style.inner)
-    //         .map_err(|e| PyValueError::new_err(e.to_string()))
-    // }
-
-    // /// Write a number with style
-    // fn write_number_with_style(
-    //     &mut self,
-    //     sheet: usize,
-    //     row: usize,
-    //     col: usize,
-    //     value: f64,
-    //     style: &PyStyle,
-    // ) -> PyResult<()> {
-    //     let writer = self.inner.as_mut()
-    //         .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
-    //
-    //     writer.write_number_with_style(sheet, row, col, value, &style.inner)
-    //         .map_err(|e| PyValueError::new_err(e.to_string()))
-    // }
-
-    // TODO: Re-enable chart insertion methods after fixing charts module
-    // /// Insert a line chart
-    // fn insert_line_chart(&mut self, sheet: usize, chart: &PyLineChart) -> PyResult<()> {
-    //     let writer = self.inner.as_mut()
-    //         .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
-    //
-    //     writer.insert_line_chart(sheet, &chart.inner)
-    //         .map_err(|e| PyValueError::new_err(e.to_string()))
-    // }
+        writer.write_url(sheet, row, col, url)?;
+        Ok(())
+    }
+
+    /// Write a string with style
+    fn write_string_with_style(
+        &mut self,
+        sheet: usize,
+        row: usize,
+        col: usize,
+        value: &str,
+        style: &PyStyle,
+    ) -> PyResult<()> {
+        let writer = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
+
+        writer.write_string_with_style(sheet, row, col, value, &style.inner)?;
+        Ok(())
+    }
+
+    /// Write a number with style
+    fn write_number_with_style(
+        &mut self,
+        sheet: usize,
+        row: usize,
+        col: usize,
+        value: f64,
+        style: &PyStyle,
+    ) -> PyResult<()> {
+        let writer = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
+
+        writer.write_number_with_style(sheet, row, col, value, &style.inner)?;
+        Ok(())
+    }
+
+    /// Insert a line chart, returning the `(row, col)` where it was placed
+    fn insert_line_chart(&mut self, sheet: usize, chart: &PyLineChart) -> PyResult<(u32, u16)> {
+        let writer = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
+
+        Ok(writer.insert_line_chart(sheet, &chart.inner)?)
+    }
+
+    /// Insert a column chart, returning the `(row, col)` where it was placed
+    fn insert_column_chart(&mut self, sheet: usize, chart: &PyColumnChart) -> PyResult<(u32, u16)> {
+        let writer = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
+
+        Ok(writer.insert_column_chart(sheet, &chart.inner)?)
+    }
+
+    /// Insert a bar chart, returning the `(row, col)` where it was placed
+    fn insert_bar_chart(&mut self, sheet: usize, chart: &PyBarChart) -> PyResult<(u32, u16)> {
+        let writer = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
+
+        Ok(writer.insert_bar_chart(sheet, &chart.inner)?)
+    }
+
+    /// Insert a pie chart, returning the `(row, col)` where it was placed
+    fn insert_pie_chart(&mut self, sheet: usize, chart: &PyPieChart) -> PyResult<(u32, u16)> {
+        let writer = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
+
+        Ok(writer.insert_pie_chart(sheet, &chart.inner)?)
+    }
+
+    /// Insert a scatter chart, returning the `(row, col)` where it was placed
+    fn insert_scatter_chart(
+        &mut self,
+        sheet: usize,
+        chart: &PyScatterChart,
+    ) -> PyResult<(u32, u16)> {
+        let writer = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
+
+        Ok(writer.insert_scatter_chart(sheet, &chart.inner)?)
+    }
+
+    /// Insert an area chart, returning the `(row, col)` where it was placed
+    fn insert_area_chart(&mut self, sheet: usize, chart: &PyAreaChart) -> PyResult<(u32, u16)> {
+        let writer = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
+
+        Ok(writer.insert_area_chart(sheet, &chart.inner)?)
+    }
+
+    /// Insert a doughnut chart, returning the `(row, col)` where it was placed
+    fn insert_doughnut_chart(
+        &mut self,
+        sheet: usize,
+        chart: &PyDoughnutChart,
+    ) -> PyResult<(u32, u16)> {
+        let writer = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
+
+        Ok(writer.insert_doughnut_chart(sheet, &chart.inner)?)
+    }
+
+    /// Insert a stock chart, returning the `(row, col)` where it was placed
+    fn insert_stock_chart(&mut self, sheet: usize, chart: &PyStockChart) -> PyResult<(u32, u16)> {
+        let writer = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
+
+        Ok(writer.insert_stock_chart(sheet, &chart.inner)?)
+    }
+
+    /// Add data validation to a range of cells
+    fn add_data_validation(
+        &mut self,
+        sheet: usize,
+        first_row: usize,
+        first_col: usize,
+        last_row: usize,
+        last_col: usize,
+        validation: &PyDataValidation,
+    ) -> PyResult<()> {
+        let writer = self
+            .inner
+            .as_mut()
+            .ok_or_else(|| PyValueError::new_err("Writer has been consumed by save()"))?;
+
+        writer.add_data_validation(
+            sheet,
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+            &validation.inner,
+        )?;
+        Ok(())
+    }
 
     /// Save the workbook to a file
     fn save(&mut self, path: &str) -> PyResult<()> {
@@ -155,9 +300,8 @@ impl PyWriter {
             .take()
             .ok_or_else(|| PyValueError::new_err("Writer has already been saved"))?;
 
-        writer
-            .save(path)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+        writer.save(path)?;
+        Ok(())
     }
 }
 
@@ -172,8 +316,7 @@ impl PyReader {
     /// Open an Excel file for reading
     #[staticmethod]
     fn open(path: &str) -> PyResult<Self> {
-        let reader =
-            crate::reader::Reader::open(path).map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let reader = crate::reader::Reader::open(path)?;
         Ok(Self { inner: reader })
     }
 
@@ -184,10 +327,7 @@ impl PyReader {
 
     /// Get a worksheet by name and return its data as a `PyWorksheet`
     fn worksheet(&mut self, name: &str) -> PyResult<PyWorksheet> {
-        let range = self
-            .inner
-            .worksheet_range(name)
-            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        let range = self.inner.worksheet_range(name)?;
         Ok(PyWorksheet { range })
     }
 }
@@ -216,6 +356,17 @@ impl PyWorksheet {
         self.range.get((row, col)).and_then(DataType::get_float)
     }
 
+    /// Get cell value as a native Python object
+    ///
+    /// Returns a `str`, `float`, `bool`, or `None` depending on the cell type.
+    /// Integers and dates are returned as `float` (dates as Excel serial numbers).
+    fn get_cell(&self, py: Python<'_>, row: usize, col: usize) -> PyResult<PyObject> {
+        match self.range.get((row, col)) {
+            Some(cell) => data_to_py(py, cell),
+            None => Ok(py.None()),
+        }
+    }
+
     /// Get dimensions (rows, cols)
     fn dimensions(&self) -> (usize, usize) {
         self.range.get_size()
@@ -243,6 +394,44 @@ impl PyWorksheet {
         result
     }
 
+    /// Convert worksheet to a list of dicts keyed by the header row
+    ///
+    /// The first row is used as the header. Values are native Python types
+    /// as returned by `get_cell()`. Repeated headers are suffixed (`name`,
+    /// `name_2`, ...) and blank headers are named `col_N` (zero-based).
+    fn to_records<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        let headers =
+            crate::reader::Reader::unique_headers(self.range.rows().next(), self.range.width());
+
+        self.range
+            .rows()
+            .skip(1)
+            .map(|row| {
+                let record = PyDict::new(py);
+                for (header, cell) in headers.iter().zip(row) {
+                    record.set_item(header, data_to_py(py, cell)?)?;
+                }
+                Ok(record)
+            })
+            .collect()
+    }
+
+    /// Convert worksheet to a list of columns with native Python values
+    fn to_columns(&self, py: Python<'_>) -> PyResult<Vec<Vec<PyObject>>> {
+        let (rows, cols) = self.range.get_size();
+
+        (0..cols)
+            .map(|col| {
+                (0..rows)
+                    .map(|row| match self.range.get((row, col)) {
+                        Some(cell) => data_to_py(py, cell),
+                        None => Ok(py.None()),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
     /// Iterate over rows
     fn __iter__(slf: PyRef<'_, Self>) -> PyWorksheetIterator {
         PyWorksheetIterator {
@@ -252,6 +441,20 @@ impl PyWorksheet {
     }
 }
 
+/// Convert a cell value to a native Python object
+#[allow(clippy::cast_precision_loss)]
+fn data_to_py(py: Python<'_>, cell: &Data) -> PyResult<PyObject> {
+    match cell {
+        Data::Empty => Ok(py.None()),
+        Data::Bool(b) => b.into_py_any(py),
+        Data::Int(i) => (*i as f64).into_py_any(py),
+        Data::Float(f) => f.into_py_any(py),
+        Data::DateTime(dt) => dt.as_f64().into_py_any(py),
+        Data::String(s) | Data::DateTimeIso(s) | Data::DurationIso(s) => s.into_py_any(py),
+        Data::Error(e) => e.to_string().into_py_any(py),
+    }
+}
+
 /// Iterator for worksheet rows
 #[pyclass]
 struct PyWorksheetIterator {
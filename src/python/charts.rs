@@ -1,12 +1,13 @@
 //! Python bindings for chart types
 
 use crate::charts::{
-    AreaChart, BarChart, ChartPosition, ColumnChart, DataSeries, DoughnutChart, LineChart,
-    PieChart, ScatterChart,
+    AreaChart, BarChart, ChartPosition, ColumnChart, DataSeries, DoughnutChart, EmptyCellsAs,
+    LineChart, PieChart, ScatterChart, StockChart,
 };
+use crate::python::styles::PyNumberFormat;
 use pyo3::prelude::*;
 
-/// Python wrapper for DataSeries
+/// Python wrapper for `DataSeries`
 #[pyclass(name = "DataSeries")]
 #[derive(Clone)]
 pub struct PyDataSeries {
@@ -24,19 +25,44 @@ impl PyDataSeries {
     }
 
     /// Set series name
-    fn name(mut slf: PyRefMut<'_, Self>, name: &str) -> PyRefMut<'_, Self> {
-        slf.inner = std::mem::take(&mut slf.inner).name(name);
+    fn name<'py>(mut slf: PyRefMut<'py, Self>, name: &str) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().name(name);
         slf
     }
 
     /// Set categories range (X-axis)
-    fn categories(mut slf: PyRefMut<'_, Self>, categories: &str) -> PyRefMut<'_, Self> {
-        slf.inner = std::mem::take(&mut slf.inner).categories(categories);
+    fn categories<'py>(mut slf: PyRefMut<'py, Self>, categories: &str) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().categories(categories);
+        slf
+    }
+
+    /// Set two-level categories, shown as a grouped category axis
+    fn categories_multilevel<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        primary: &str,
+        secondary: &str,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().categories_multilevel(primary, secondary);
+        slf
+    }
+
+    /// Fill the series with a linear gradient of `(hex color, position)` stops
+    #[allow(clippy::needless_pass_by_value)] // pyo3 extracts owned arguments
+    fn gradient_fill(
+        mut slf: PyRefMut<'_, Self>,
+        stops: Vec<(String, u8)>,
+        angle: u16,
+    ) -> PyRefMut<'_, Self> {
+        let stops: Vec<(&str, u8)> = stops
+            .iter()
+            .map(|(color, position)| (color.as_str(), *position))
+            .collect();
+        slf.inner = slf.inner.clone().gradient_fill(&stops, angle);
         slf
     }
 }
 
-/// Python wrapper for ChartPosition
+/// Python wrapper for `ChartPosition`
 #[pyclass(name = "ChartPosition")]
 #[derive(Clone)]
 pub struct PyChartPosition {
@@ -55,18 +81,18 @@ impl PyChartPosition {
 
     /// Set chart width in pixels
     fn width(mut slf: PyRefMut<'_, Self>, width: u32) -> PyRefMut<'_, Self> {
-        slf.inner = std::mem::take(&mut slf.inner).width(width);
+        slf.inner = slf.inner.clone().width(width);
         slf
     }
 
     /// Set chart height in pixels
     fn height(mut slf: PyRefMut<'_, Self>, height: u32) -> PyRefMut<'_, Self> {
-        slf.inner = std::mem::take(&mut slf.inner).height(height);
+        slf.inner = slf.inner.clone().height(height);
         slf
     }
 }
 
-/// Python wrapper for LineChart
+/// Python wrapper for `LineChart`
 #[pyclass(name = "LineChart")]
 pub struct PyLineChart {
     pub(crate) inner: LineChart,
@@ -83,43 +109,151 @@ impl PyLineChart {
     }
 
     /// Set chart title
-    fn title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).title(title);
         slf
     }
 
     /// Set X-axis title
-    fn x_axis_title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn x_axis_title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).x_axis_title(title);
         slf
     }
 
     /// Set Y-axis title
-    fn y_axis_title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn y_axis_title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).y_axis_title(title);
         slf
     }
 
     /// Add a data series to the chart
-    fn add_series(mut slf: PyRefMut<'_, Self>, series: &PyDataSeries) -> PyRefMut<'_, Self> {
+    fn add_series<'py>(mut slf: PyRefMut<'py, Self>, series: &PyDataSeries) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).add_series(series.inner.clone());
         slf
     }
 
     /// Set chart position on worksheet
-    fn position(mut slf: PyRefMut<'_, Self>, position: &PyChartPosition) -> PyRefMut<'_, Self> {
+    fn position<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        position: &PyChartPosition,
+    ) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).position(position.inner.clone());
         slf
     }
 
+    /// Set the background color of the whole chart
+    fn chart_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).chart_area_fill(color);
+        slf
+    }
+
+    /// Set the background color of the plot area
+    fn plot_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).plot_area_fill(color);
+        slf
+    }
+
     /// Set whether to show legend
     fn show_legend(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
         slf.inner = std::mem::take(&mut slf.inner).show_legend(show);
         slf
     }
+
+    /// Set whether to hide the X-axis
+    fn hide_x_axis(mut slf: PyRefMut<'_, Self>, hide: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).hide_x_axis(hide);
+        slf
+    }
+
+    /// Set whether to hide the Y-axis
+    fn hide_y_axis(mut slf: PyRefMut<'_, Self>, hide: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).hide_y_axis(hide);
+        slf
+    }
+
+    /// Set whether to reverse the X-axis
+    fn reverse_x_axis(mut slf: PyRefMut<'_, Self>, reverse: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).reverse_x_axis(reverse);
+        slf
+    }
+
+    /// Set whether to reverse the Y-axis
+    fn reverse_y_axis(mut slf: PyRefMut<'_, Self>, reverse: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).reverse_y_axis(reverse);
+        slf
+    }
+
+    /// Set whether the X-axis is a date axis
+    fn date_axis(mut slf: PyRefMut<'_, Self>, enable: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).date_axis(enable);
+        slf
+    }
+
+    /// Set the number format for X-axis labels
+    fn x_axis_number_format<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        format: &PyNumberFormat,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).x_axis_number_format(format.inner.clone());
+        slf
+    }
+
+    /// Set the number format for Y-axis labels
+    fn y_axis_number_format<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        format: &PyNumberFormat,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).y_axis_number_format(format.inner.clone());
+        slf
+    }
+
+    /// Set how empty cells are plotted (0=gaps, 1=zero, 2=connect)
+    fn empty_cells(mut slf: PyRefMut<'_, Self>, empty_cells: u8) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).empty_cells(empty_cells_from_u8(empty_cells));
+        slf
+    }
+
+    /// Set whether to draw up/down bars
+    fn up_down_bars(mut slf: PyRefMut<'_, Self>, enable: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).up_down_bars(enable);
+        slf
+    }
+
+    /// Set whether to draw high-low lines
+    fn high_low_lines(mut slf: PyRefMut<'_, Self>, enable: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).high_low_lines(enable);
+        slf
+    }
+
+    /// Set whether series are taken from rows rather than columns
+    fn plot_by_rows(mut slf: PyRefMut<'_, Self>, enable: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).plot_by_rows(enable);
+        slf
+    }
+
+    /// Set whether to show a data table below the chart
+    fn show_data_table(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).show_data_table(show);
+        slf
+    }
+
+    /// Set whether the data table shows each series' legend key
+    fn data_table_legend_keys(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).data_table_legend_keys(show);
+        slf
+    }
 }
 
-/// Python wrapper for ColumnChart
+/// Helper function to convert u8 to `EmptyCellsAs`
+fn empty_cells_from_u8(empty_cells: u8) -> EmptyCellsAs {
+    match empty_cells {
+        1 => EmptyCellsAs::Zero,
+        2 => EmptyCellsAs::Connect,
+        _ => EmptyCellsAs::Gaps,
+    }
+}
+
+/// Python wrapper for `ColumnChart`
 #[pyclass(name = "ColumnChart")]
 pub struct PyColumnChart {
     pub(crate) inner: ColumnChart,
@@ -136,49 +270,130 @@ impl PyColumnChart {
     }
 
     /// Set chart title
-    fn title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).title(title);
         slf
     }
 
     /// Set X-axis title
-    fn x_axis_title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn x_axis_title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).x_axis_title(title);
         slf
     }
 
     /// Set Y-axis title
-    fn y_axis_title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn y_axis_title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).y_axis_title(title);
         slf
     }
 
     /// Add a data series to the chart
-    fn add_series(mut slf: PyRefMut<'_, Self>, series: &PyDataSeries) -> PyRefMut<'_, Self> {
+    fn add_series<'py>(mut slf: PyRefMut<'py, Self>, series: &PyDataSeries) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).add_series(series.inner.clone());
         slf
     }
 
     /// Set chart position on worksheet
-    fn position(mut slf: PyRefMut<'_, Self>, position: &PyChartPosition) -> PyRefMut<'_, Self> {
+    fn position<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        position: &PyChartPosition,
+    ) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).position(position.inner.clone());
         slf
     }
 
+    /// Set the background color of the whole chart
+    fn chart_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).chart_area_fill(color);
+        slf
+    }
+
+    /// Set the background color of the plot area
+    fn plot_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).plot_area_fill(color);
+        slf
+    }
+
     /// Set whether to show legend
     fn show_legend(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
         slf.inner = std::mem::take(&mut slf.inner).show_legend(show);
         slf
     }
 
+    /// Set whether to hide the X-axis
+    fn hide_x_axis(mut slf: PyRefMut<'_, Self>, hide: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).hide_x_axis(hide);
+        slf
+    }
+
+    /// Set whether to hide the Y-axis
+    fn hide_y_axis(mut slf: PyRefMut<'_, Self>, hide: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).hide_y_axis(hide);
+        slf
+    }
+
+    /// Set whether to reverse the X-axis
+    fn reverse_x_axis(mut slf: PyRefMut<'_, Self>, reverse: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).reverse_x_axis(reverse);
+        slf
+    }
+
+    /// Set whether to reverse the Y-axis
+    fn reverse_y_axis(mut slf: PyRefMut<'_, Self>, reverse: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).reverse_y_axis(reverse);
+        slf
+    }
+
+    /// Set whether the X-axis is a date axis
+    fn date_axis(mut slf: PyRefMut<'_, Self>, enable: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).date_axis(enable);
+        slf
+    }
+
+    /// Set the number format for X-axis labels
+    fn x_axis_number_format<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        format: &PyNumberFormat,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).x_axis_number_format(format.inner.clone());
+        slf
+    }
+
+    /// Set the number format for Y-axis labels
+    fn y_axis_number_format<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        format: &PyNumberFormat,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).y_axis_number_format(format.inner.clone());
+        slf
+    }
+
     /// Set whether columns should be stacked
     fn stacked(mut slf: PyRefMut<'_, Self>, stacked: bool) -> PyRefMut<'_, Self> {
         slf.inner = std::mem::take(&mut slf.inner).stacked(stacked);
         slf
     }
+
+    /// Set whether series are taken from rows rather than columns
+    fn plot_by_rows(mut slf: PyRefMut<'_, Self>, enable: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).plot_by_rows(enable);
+        slf
+    }
+
+    /// Set whether to show a data table below the chart
+    fn show_data_table(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).show_data_table(show);
+        slf
+    }
+
+    /// Set whether the data table shows each series' legend key
+    fn data_table_legend_keys(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).data_table_legend_keys(show);
+        slf
+    }
 }
 
-/// Python wrapper for BarChart
+/// Python wrapper for `BarChart`
 #[pyclass(name = "BarChart")]
 pub struct PyBarChart {
     pub(crate) inner: BarChart,
@@ -195,49 +410,124 @@ impl PyBarChart {
     }
 
     /// Set chart title
-    fn title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).title(title);
         slf
     }
 
     /// Set X-axis title
-    fn x_axis_title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn x_axis_title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).x_axis_title(title);
         slf
     }
 
     /// Set Y-axis title
-    fn y_axis_title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn y_axis_title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).y_axis_title(title);
         slf
     }
 
     /// Add a data series to the chart
-    fn add_series(mut slf: PyRefMut<'_, Self>, series: &PyDataSeries) -> PyRefMut<'_, Self> {
+    fn add_series<'py>(mut slf: PyRefMut<'py, Self>, series: &PyDataSeries) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).add_series(series.inner.clone());
         slf
     }
 
     /// Set chart position on worksheet
-    fn position(mut slf: PyRefMut<'_, Self>, position: &PyChartPosition) -> PyRefMut<'_, Self> {
+    fn position<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        position: &PyChartPosition,
+    ) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).position(position.inner.clone());
         slf
     }
 
+    /// Set the background color of the whole chart
+    fn chart_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).chart_area_fill(color);
+        slf
+    }
+
+    /// Set the background color of the plot area
+    fn plot_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).plot_area_fill(color);
+        slf
+    }
+
     /// Set whether to show legend
     fn show_legend(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
         slf.inner = std::mem::take(&mut slf.inner).show_legend(show);
         slf
     }
 
+    /// Set whether to hide the X-axis
+    fn hide_x_axis(mut slf: PyRefMut<'_, Self>, hide: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).hide_x_axis(hide);
+        slf
+    }
+
+    /// Set whether to hide the Y-axis
+    fn hide_y_axis(mut slf: PyRefMut<'_, Self>, hide: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).hide_y_axis(hide);
+        slf
+    }
+
+    /// Set whether to reverse the X-axis
+    fn reverse_x_axis(mut slf: PyRefMut<'_, Self>, reverse: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).reverse_x_axis(reverse);
+        slf
+    }
+
+    /// Set whether to reverse the Y-axis
+    fn reverse_y_axis(mut slf: PyRefMut<'_, Self>, reverse: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).reverse_y_axis(reverse);
+        slf
+    }
+
+    /// Set the number format for X-axis labels
+    fn x_axis_number_format<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        format: &PyNumberFormat,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).x_axis_number_format(format.inner.clone());
+        slf
+    }
+
+    /// Set the number format for Y-axis labels
+    fn y_axis_number_format<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        format: &PyNumberFormat,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).y_axis_number_format(format.inner.clone());
+        slf
+    }
+
     /// Set whether bars should be stacked
     fn stacked(mut slf: PyRefMut<'_, Self>, stacked: bool) -> PyRefMut<'_, Self> {
         slf.inner = std::mem::take(&mut slf.inner).stacked(stacked);
         slf
     }
+
+    /// Set whether series are taken from rows rather than columns
+    fn plot_by_rows(mut slf: PyRefMut<'_, Self>, enable: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).plot_by_rows(enable);
+        slf
+    }
+
+    /// Set whether to show a data table below the chart
+    fn show_data_table(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).show_data_table(show);
+        slf
+    }
+
+    /// Set whether the data table shows each series' legend key
+    fn data_table_legend_keys(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).data_table_legend_keys(show);
+        slf
+    }
 }
 
-/// Python wrapper for PieChart
+/// Python wrapper for `PieChart`
 #[pyclass(name = "PieChart")]
 pub struct PyPieChart {
     pub(crate) inner: PieChart,
@@ -254,23 +544,38 @@ impl PyPieChart {
     }
 
     /// Set chart title
-    fn title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).title(title);
         slf
     }
 
     /// Add a data series to the chart
-    fn add_series(mut slf: PyRefMut<'_, Self>, series: &PyDataSeries) -> PyRefMut<'_, Self> {
+    fn add_series<'py>(mut slf: PyRefMut<'py, Self>, series: &PyDataSeries) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).add_series(series.inner.clone());
         slf
     }
 
     /// Set chart position on worksheet
-    fn position(mut slf: PyRefMut<'_, Self>, position: &PyChartPosition) -> PyRefMut<'_, Self> {
+    fn position<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        position: &PyChartPosition,
+    ) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).position(position.inner.clone());
         slf
     }
 
+    /// Set the background color of the whole chart
+    fn chart_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).chart_area_fill(color);
+        slf
+    }
+
+    /// Set the background color of the plot area
+    fn plot_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).plot_area_fill(color);
+        slf
+    }
+
     /// Set whether to show legend
     fn show_legend(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
         slf.inner = std::mem::take(&mut slf.inner).show_legend(show);
@@ -278,7 +583,7 @@ impl PyPieChart {
     }
 }
 
-/// Python wrapper for ScatterChart
+/// Python wrapper for `ScatterChart`
 #[pyclass(name = "ScatterChart")]
 pub struct PyScatterChart {
     pub(crate) inner: ScatterChart,
@@ -295,43 +600,100 @@ impl PyScatterChart {
     }
 
     /// Set chart title
-    fn title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).title(title);
         slf
     }
 
     /// Set X-axis title
-    fn x_axis_title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn x_axis_title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).x_axis_title(title);
         slf
     }
 
     /// Set Y-axis title
-    fn y_axis_title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn y_axis_title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).y_axis_title(title);
         slf
     }
 
     /// Add a data series to the chart
-    fn add_series(mut slf: PyRefMut<'_, Self>, series: &PyDataSeries) -> PyRefMut<'_, Self> {
+    fn add_series<'py>(mut slf: PyRefMut<'py, Self>, series: &PyDataSeries) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).add_series(series.inner.clone());
         slf
     }
 
     /// Set chart position on worksheet
-    fn position(mut slf: PyRefMut<'_, Self>, position: &PyChartPosition) -> PyRefMut<'_, Self> {
+    fn position<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        position: &PyChartPosition,
+    ) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).position(position.inner.clone());
         slf
     }
 
+    /// Set the background color of the whole chart
+    fn chart_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).chart_area_fill(color);
+        slf
+    }
+
+    /// Set the background color of the plot area
+    fn plot_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).plot_area_fill(color);
+        slf
+    }
+
     /// Set whether to show legend
     fn show_legend(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
         slf.inner = std::mem::take(&mut slf.inner).show_legend(show);
         slf
     }
+
+    /// Set whether to hide the X-axis
+    fn hide_x_axis(mut slf: PyRefMut<'_, Self>, hide: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).hide_x_axis(hide);
+        slf
+    }
+
+    /// Set whether to hide the Y-axis
+    fn hide_y_axis(mut slf: PyRefMut<'_, Self>, hide: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).hide_y_axis(hide);
+        slf
+    }
+
+    /// Set whether to reverse the X-axis
+    fn reverse_x_axis(mut slf: PyRefMut<'_, Self>, reverse: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).reverse_x_axis(reverse);
+        slf
+    }
+
+    /// Set whether to reverse the Y-axis
+    fn reverse_y_axis(mut slf: PyRefMut<'_, Self>, reverse: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).reverse_y_axis(reverse);
+        slf
+    }
+
+    /// Set the number format for X-axis labels
+    fn x_axis_number_format<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        format: &PyNumberFormat,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).x_axis_number_format(format.inner.clone());
+        slf
+    }
+
+    /// Set the number format for Y-axis labels
+    fn y_axis_number_format<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        format: &PyNumberFormat,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).y_axis_number_format(format.inner.clone());
+        slf
+    }
 }
 
-/// Python wrapper for AreaChart
+/// Python wrapper for `AreaChart`
 #[pyclass(name = "AreaChart")]
 pub struct PyAreaChart {
     pub(crate) inner: AreaChart,
@@ -348,49 +710,112 @@ impl PyAreaChart {
     }
 
     /// Set chart title
-    fn title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).title(title);
         slf
     }
 
     /// Set X-axis title
-    fn x_axis_title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn x_axis_title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).x_axis_title(title);
         slf
     }
 
     /// Set Y-axis title
-    fn y_axis_title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn y_axis_title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).y_axis_title(title);
         slf
     }
 
     /// Add a data series to the chart
-    fn add_series(mut slf: PyRefMut<'_, Self>, series: &PyDataSeries) -> PyRefMut<'_, Self> {
+    fn add_series<'py>(mut slf: PyRefMut<'py, Self>, series: &PyDataSeries) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).add_series(series.inner.clone());
         slf
     }
 
     /// Set chart position on worksheet
-    fn position(mut slf: PyRefMut<'_, Self>, position: &PyChartPosition) -> PyRefMut<'_, Self> {
+    fn position<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        position: &PyChartPosition,
+    ) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).position(position.inner.clone());
         slf
     }
 
+    /// Set the background color of the whole chart
+    fn chart_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).chart_area_fill(color);
+        slf
+    }
+
+    /// Set the background color of the plot area
+    fn plot_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).plot_area_fill(color);
+        slf
+    }
+
     /// Set whether to show legend
     fn show_legend(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
         slf.inner = std::mem::take(&mut slf.inner).show_legend(show);
         slf
     }
 
+    /// Set whether to hide the X-axis
+    fn hide_x_axis(mut slf: PyRefMut<'_, Self>, hide: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).hide_x_axis(hide);
+        slf
+    }
+
+    /// Set whether to hide the Y-axis
+    fn hide_y_axis(mut slf: PyRefMut<'_, Self>, hide: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).hide_y_axis(hide);
+        slf
+    }
+
+    /// Set whether to reverse the X-axis
+    fn reverse_x_axis(mut slf: PyRefMut<'_, Self>, reverse: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).reverse_x_axis(reverse);
+        slf
+    }
+
+    /// Set whether to reverse the Y-axis
+    fn reverse_y_axis(mut slf: PyRefMut<'_, Self>, reverse: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).reverse_y_axis(reverse);
+        slf
+    }
+
+    /// Set the number format for X-axis labels
+    fn x_axis_number_format<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        format: &PyNumberFormat,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).x_axis_number_format(format.inner.clone());
+        slf
+    }
+
+    /// Set the number format for Y-axis labels
+    fn y_axis_number_format<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        format: &PyNumberFormat,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).y_axis_number_format(format.inner.clone());
+        slf
+    }
+
     /// Set whether areas should be stacked
     fn stacked(mut slf: PyRefMut<'_, Self>, stacked: bool) -> PyRefMut<'_, Self> {
         slf.inner = std::mem::take(&mut slf.inner).stacked(stacked);
         slf
     }
+
+    /// Set whether series are taken from rows rather than columns
+    fn plot_by_rows(mut slf: PyRefMut<'_, Self>, enable: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).plot_by_rows(enable);
+        slf
+    }
 }
 
-/// Python wrapper for DoughnutChart
+/// Python wrapper for `DoughnutChart`
 #[pyclass(name = "DoughnutChart")]
 pub struct PyDoughnutChart {
     pub(crate) inner: DoughnutChart,
@@ -407,26 +832,115 @@ impl PyDoughnutChart {
     }
 
     /// Set chart title
-    fn title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).title(title);
         slf
     }
 
     /// Add a data series to the chart
-    fn add_series(mut slf: PyRefMut<'_, Self>, series: &PyDataSeries) -> PyRefMut<'_, Self> {
+    fn add_series<'py>(mut slf: PyRefMut<'py, Self>, series: &PyDataSeries) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).add_series(series.inner.clone());
         slf
     }
 
     /// Set chart position on worksheet
-    fn position(mut slf: PyRefMut<'_, Self>, position: &PyChartPosition) -> PyRefMut<'_, Self> {
+    fn position<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        position: &PyChartPosition,
+    ) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).position(position.inner.clone());
         slf
     }
 
+    /// Set the background color of the whole chart
+    fn chart_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).chart_area_fill(color);
+        slf
+    }
+
+    /// Set the background color of the plot area
+    fn plot_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).plot_area_fill(color);
+        slf
+    }
+
     /// Set whether to show legend
     fn show_legend(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
         slf.inner = std::mem::take(&mut slf.inner).show_legend(show);
         slf
     }
 }
+
+/// Python wrapper for `StockChart`
+#[pyclass(name = "StockChart")]
+pub struct PyStockChart {
+    pub(crate) inner: StockChart,
+}
+
+#[pymethods]
+impl PyStockChart {
+    /// Create a new high-low-close stock chart
+    #[new]
+    fn new(high: &str, low: &str, close: &str) -> Self {
+        Self {
+            inner: StockChart::new(high, low, close),
+        }
+    }
+
+    /// Set chart title
+    fn title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().title(title);
+        slf
+    }
+
+    /// Set X-axis title
+    fn x_axis_title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().x_axis_title(title);
+        slf
+    }
+
+    /// Set Y-axis title
+    fn y_axis_title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().y_axis_title(title);
+        slf
+    }
+
+    /// Set categories range (X-axis)
+    fn categories<'py>(mut slf: PyRefMut<'py, Self>, categories: &str) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().categories(categories);
+        slf
+    }
+
+    /// Set opening prices range
+    fn open<'py>(mut slf: PyRefMut<'py, Self>, open: &str) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().open(open);
+        slf
+    }
+
+    /// Set chart position on worksheet
+    fn position<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        position: &PyChartPosition,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().position(position.inner.clone());
+        slf
+    }
+
+    /// Set the background color of the whole chart
+    fn chart_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().chart_area_fill(color);
+        slf
+    }
+
+    /// Set the background color of the plot area
+    fn plot_area_fill<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().plot_area_fill(color);
+        slf
+    }
+
+    /// Set whether to show legend
+    fn show_legend(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
+        slf.inner = slf.inner.clone().show_legend(show);
+        slf
+    }
+}
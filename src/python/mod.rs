@@ -3,17 +3,50 @@
 //! Provides Python bindings for the `XlsXpress` library using `PyO3`.
 
 pub mod bindings;
-// TODO: Fix compilation errors in these modules before re-enabling
-// pub mod charts;
-// pub mod styles;
-// pub mod validation;
+pub mod charts;
+pub mod styles;
+pub mod validation;
 
+use crate::error::Error;
+use pyo3::exceptions::{PyFileNotFoundError, PyIOError, PyValueError};
 use pyo3::prelude::*;
+use std::io::ErrorKind;
 
 // Re-export public types
-// pub use charts::*;
-// pub use styles::*;
-// pub use validation::*;
+pub use charts::*;
+pub use styles::*;
+pub use validation::*;
+
+/// Raise library errors as the closest built-in Python exception
+///
+/// Missing files become `FileNotFoundError`, other I/O failures `OSError`,
+/// and everything else (bad input, malformed workbooks) `ValueError`.
+impl From<Error> for PyErr {
+    fn from(err: Error) -> Self {
+        let message = err.to_string();
+        if is_file_not_found(&err) {
+            PyFileNotFoundError::new_err(message)
+        } else if matches!(
+            err,
+            Error::FileRead { .. } | Error::FileWrite { .. } | Error::Io(_)
+        ) {
+            PyIOError::new_err(message)
+        } else {
+            PyValueError::new_err(message)
+        }
+    }
+}
+
+/// Whether an error was caused by a file that doesn't exist
+///
+/// calamine wraps the I/O error of opening a file in its own error types, so
+/// the whole source chain is searched.
+fn is_file_not_found(err: &Error) -> bool {
+    std::iter::successors(Some(err as &dyn std::error::Error), |e| e.source()).any(|e| {
+        e.downcast_ref::<std::io::Error>()
+            .is_some_and(|io| io.kind() == ErrorKind::NotFound)
+    })
+}
 
 /// `XlsXpress` Python module initialization
 #[pymodule]
@@ -23,37 +56,54 @@ fn xlsxpress(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<bindings::PyReader>()?;
     m.add_class::<bindings::PyWorksheet>()?;
 
-    // TODO: Re-enable after fixing compilation errors
-    // // Chart classes
-    // m.add_class::<charts::PyLineChart>()?;
-    // m.add_class::<charts::PyColumnChart>()?;
-    // m.add_class::<charts::PyBarChart>()?;
-    // m.add_class::<charts::PyPieChart>()?;
-    // m.add_class::<charts::PyScatterChart>()?;
-    // m.add_class::<charts::PyAreaChart>()?;
-    // m.add_class::<charts::PyDoughnutChart>()?;
-    // m.add_class::<charts::PyDataSeries>()?;
-    // m.add_class::<charts::PyChartPosition>()?;
-    //
-    // // Style classes
-    // m.add_class::<styles::PyFont>()?;
-    // m.add_class::<styles::PyFill>()?;
-    // m.add_class::<styles::PyBorder>()?;
-    // m.add_class::<styles::PyAlignment>()?;
-    // m.add_class::<styles::PyNumberFormat>()?;
-    // m.add_class::<styles::PyStyle>()?;
-    //
-    // // Validation classes
-    // m.add_class::<validation::PyListValidation>()?;
-    // m.add_class::<validation::PyNumberValidation>()?;
-    // m.add_class::<validation::PyDateValidation>()?;
-    // m.add_class::<validation::PyTextValidation>()?;
-    // m.add_class::<validation::PyValidationError>()?;
-    // m.add_class::<validation::PyValidationWarning>()?;
-    // m.add_class::<validation::PyDataValidation>()?;
+    // Style classes
+    m.add_class::<styles::PyFont>()?;
+    m.add_class::<styles::PyFill>()?;
+    m.add_class::<styles::PyBorder>()?;
+    m.add_class::<styles::PyAlignment>()?;
+    m.add_class::<styles::PyNumberFormat>()?;
+    m.add_class::<styles::PyStyle>()?;
+
+    // Chart classes
+    m.add_class::<charts::PyLineChart>()?;
+    m.add_class::<charts::PyColumnChart>()?;
+    m.add_class::<charts::PyBarChart>()?;
+    m.add_class::<charts::PyPieChart>()?;
+    m.add_class::<charts::PyScatterChart>()?;
+    m.add_class::<charts::PyAreaChart>()?;
+    m.add_class::<charts::PyDoughnutChart>()?;
+    m.add_class::<charts::PyStockChart>()?;
+    m.add_class::<charts::PyDataSeries>()?;
+    m.add_class::<charts::PyChartPosition>()?;
+
+    // Validation classes
+    m.add_class::<validation::PyListValidation>()?;
+    m.add_class::<validation::PyNumberValidation>()?;
+    m.add_class::<validation::PyDateValidation>()?;
+    m.add_class::<validation::PyTextValidation>()?;
+    m.add_class::<validation::PyValidationError>()?;
+    m.add_class::<validation::PyValidationWarning>()?;
+    m.add_class::<validation::PyDataValidation>()?;
 
     // Module version
     m.add("__version__", env!("CARGO_PKG_VERSION"))?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test a missing workbook is recognised as file not found
+    #[test]
+    fn test_is_file_not_found() {
+        let err = crate::reader::Reader::open("tests/fixtures/missing.xlsx")
+            .err()
+            .expect("missing file should not open");
+        assert!(is_file_not_found(&err));
+
+        let err = Error::invalid_format("not a workbook");
+        assert!(!is_file_not_found(&err));
+    }
+}
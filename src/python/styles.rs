@@ -22,7 +22,7 @@ impl PyFont {
     }
 
     /// Set font name
-    fn name(mut slf: PyRefMut<'_, Self>, name: &str) -> PyRefMut<'_, Self> {
+    fn name<'py>(mut slf: PyRefMut<'py, Self>, name: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).name(name);
         slf
     }
@@ -46,7 +46,7 @@ impl PyFont {
     }
 
     /// Set text color from hex string
-    fn color(mut slf: PyRefMut<'_, Self>, color: &str) -> PyRefMut<'_, Self> {
+    fn color<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).color(color);
         slf
     }
@@ -84,15 +84,7 @@ impl PyFill {
     /// Create a pattern fill
     #[staticmethod]
     fn pattern(pattern: u8) -> Self {
-        let pattern_enum = match pattern {
-            0 => FillPattern::Solid,
-            1 => FillPattern::DarkGray,
-            2 => FillPattern::MediumGray,
-            3 => FillPattern::LightGray,
-            4 => FillPattern::Gray125,
-            5 => FillPattern::Gray0625,
-            _ => FillPattern::Solid,
-        };
+        let pattern_enum = fill_pattern_from_u8(pattern);
         Self {
             inner: Fill::pattern(pattern_enum),
         }
@@ -100,15 +92,7 @@ impl PyFill {
 
     /// Set the fill pattern
     fn set_pattern(mut slf: PyRefMut<'_, Self>, pattern: u8) -> PyRefMut<'_, Self> {
-        let pattern_enum = match pattern {
-            0 => FillPattern::Solid,
-            1 => FillPattern::DarkGray,
-            2 => FillPattern::MediumGray,
-            3 => FillPattern::LightGray,
-            4 => FillPattern::Gray125,
-            5 => FillPattern::Gray0625,
-            _ => FillPattern::Solid,
-        };
+        let pattern_enum = fill_pattern_from_u8(pattern);
         slf.inner = std::mem::take(&mut slf.inner).set_pattern(pattern_enum);
         slf
     }
@@ -120,7 +104,7 @@ impl PyFill {
     }
 
     /// Set background color for patterns
-    fn background_color(mut slf: PyRefMut<'_, Self>, color: &str) -> PyRefMut<'_, Self> {
+    fn background_color<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).background_color(color);
         slf
     }
@@ -196,7 +180,7 @@ impl PyBorder {
     }
 
     /// Set border color from hex string
-    fn color(mut slf: PyRefMut<'_, Self>, color: &str) -> PyRefMut<'_, Self> {
+    fn color<'py>(mut slf: PyRefMut<'py, Self>, color: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).color(color);
         slf
     }
@@ -208,7 +192,22 @@ impl PyBorder {
     }
 }
 
-/// Helper function to convert u8 to BorderStyle
+/// Helper function to convert u8 to `FillPattern`
+#[allow(clippy::match_same_arms)]
+fn fill_pattern_from_u8(pattern: u8) -> FillPattern {
+    match pattern {
+        0 => FillPattern::Solid,
+        1 => FillPattern::DarkGray,
+        2 => FillPattern::MediumGray,
+        3 => FillPattern::LightGray,
+        4 => FillPattern::Gray125,
+        5 => FillPattern::Gray0625,
+        _ => FillPattern::Solid,
+    }
+}
+
+/// Helper function to convert u8 to `BorderStyle`
+#[allow(clippy::match_same_arms)]
 fn border_style_from_u8(style: u8) -> BorderStyle {
     match style {
         0 => BorderStyle::None,
@@ -248,31 +247,14 @@ impl PyAlignment {
 
     /// Set horizontal alignment
     fn horizontal(mut slf: PyRefMut<'_, Self>, align: u8) -> PyRefMut<'_, Self> {
-        let align_enum = match align {
-            0 => HorizontalAlignment::General,
-            1 => HorizontalAlignment::Left,
-            2 => HorizontalAlignment::Center,
-            3 => HorizontalAlignment::Right,
-            4 => HorizontalAlignment::Fill,
-            5 => HorizontalAlignment::Justify,
-            6 => HorizontalAlignment::CenterAcross,
-            7 => HorizontalAlignment::Distributed,
-            _ => HorizontalAlignment::General,
-        };
+        let align_enum = horizontal_alignment_from_u8(align);
         slf.inner = std::mem::take(&mut slf.inner).horizontal(align_enum);
         slf
     }
 
     /// Set vertical alignment
     fn vertical(mut slf: PyRefMut<'_, Self>, align: u8) -> PyRefMut<'_, Self> {
-        let align_enum = match align {
-            0 => VerticalAlignment::Top,
-            1 => VerticalAlignment::Center,
-            2 => VerticalAlignment::Bottom,
-            3 => VerticalAlignment::Justify,
-            4 => VerticalAlignment::Distributed,
-            _ => VerticalAlignment::Top,
-        };
+        let align_enum = vertical_alignment_from_u8(align);
         slf.inner = std::mem::take(&mut slf.inner).vertical(align_enum);
         slf
     }
@@ -289,6 +271,12 @@ impl PyAlignment {
         slf
     }
 
+    /// Set stacked vertical text
+    fn vertical_text(mut slf: PyRefMut<'_, Self>, vertical: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).vertical_text(vertical);
+        slf
+    }
+
     /// Set indentation level (0-15)
     fn indent(mut slf: PyRefMut<'_, Self>, level: u8) -> PyRefMut<'_, Self> {
         slf.inner = std::mem::take(&mut slf.inner).indent(level);
@@ -302,7 +290,36 @@ impl PyAlignment {
     }
 }
 
-/// Python wrapper for NumberFormat
+/// Helper function to convert u8 to `HorizontalAlignment`
+#[allow(clippy::match_same_arms)]
+fn horizontal_alignment_from_u8(align: u8) -> HorizontalAlignment {
+    match align {
+        0 => HorizontalAlignment::General,
+        1 => HorizontalAlignment::Left,
+        2 => HorizontalAlignment::Center,
+        3 => HorizontalAlignment::Right,
+        4 => HorizontalAlignment::Fill,
+        5 => HorizontalAlignment::Justify,
+        6 => HorizontalAlignment::CenterAcross,
+        7 => HorizontalAlignment::Distributed,
+        _ => HorizontalAlignment::General,
+    }
+}
+
+/// Helper function to convert u8 to `VerticalAlignment`
+#[allow(clippy::match_same_arms)]
+fn vertical_alignment_from_u8(align: u8) -> VerticalAlignment {
+    match align {
+        0 => VerticalAlignment::Top,
+        1 => VerticalAlignment::Center,
+        2 => VerticalAlignment::Bottom,
+        3 => VerticalAlignment::Justify,
+        4 => VerticalAlignment::Distributed,
+        _ => VerticalAlignment::Top,
+    }
+}
+
+/// Python wrapper for `NumberFormat`
 #[pyclass(name = "NumberFormat")]
 #[derive(Clone)]
 pub struct PyNumberFormat {
@@ -425,35 +442,44 @@ impl PyStyle {
     }
 
     /// Set font styling
-    fn font(mut slf: PyRefMut<'_, Self>, font: &PyFont) -> PyRefMut<'_, Self> {
+    fn font<'py>(mut slf: PyRefMut<'py, Self>, font: &PyFont) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).font(font.inner.clone());
         slf
     }
 
     /// Set fill styling
-    fn fill(mut slf: PyRefMut<'_, Self>, fill: &PyFill) -> PyRefMut<'_, Self> {
+    fn fill<'py>(mut slf: PyRefMut<'py, Self>, fill: &PyFill) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).fill(fill.inner.clone());
         slf
     }
 
     /// Set border styling
-    fn border(mut slf: PyRefMut<'_, Self>, border: &PyBorder) -> PyRefMut<'_, Self> {
+    fn border<'py>(mut slf: PyRefMut<'py, Self>, border: &PyBorder) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).border(border.inner.clone());
         slf
     }
 
     /// Set alignment styling
-    fn alignment(mut slf: PyRefMut<'_, Self>, alignment: &PyAlignment) -> PyRefMut<'_, Self> {
+    fn alignment<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        alignment: &PyAlignment,
+    ) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).alignment(alignment.inner.clone());
         slf
     }
 
     /// Set number format styling
-    fn number_format(
-        mut slf: PyRefMut<'_, Self>,
+    fn number_format<'py>(
+        mut slf: PyRefMut<'py, Self>,
         number_format: &PyNumberFormat,
-    ) -> PyRefMut<'_, Self> {
+    ) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).number_format(number_format.inner.clone());
         slf
     }
+
+    /// Set whether the cell value is quote-prefixed
+    fn quote_prefix(mut slf: PyRefMut<'_, Self>, enable: bool) -> PyRefMut<'_, Self> {
+        slf.inner = std::mem::take(&mut slf.inner).quote_prefix(enable);
+        slf
+    }
 }
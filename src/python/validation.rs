@@ -6,7 +6,7 @@ use crate::validation::{
 };
 use pyo3::prelude::*;
 
-/// Python wrapper for ListValidation
+/// Python wrapper for `ListValidation`
 #[pyclass(name = "ListValidation")]
 #[derive(Clone)]
 pub struct PyListValidation {
@@ -23,14 +23,22 @@ impl PyListValidation {
         }
     }
 
+    /// Create a list validation sourced from a worksheet range
+    #[staticmethod]
+    fn from_range(reference: &str) -> Self {
+        Self {
+            inner: ListValidation::from_range(reference),
+        }
+    }
+
     /// Set whether to show dropdown
     fn show_dropdown(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
-        slf.inner = std::mem::take(&mut slf.inner).show_dropdown(show);
+        slf.inner = slf.inner.clone().show_dropdown(show);
         slf
     }
 }
 
-/// Python wrapper for NumberValidation
+/// Python wrapper for `NumberValidation`
 #[pyclass(name = "NumberValidation")]
 #[derive(Clone)]
 pub struct PyNumberValidation {
@@ -72,7 +80,7 @@ impl PyNumberValidation {
     }
 }
 
-/// Python wrapper for DateValidation
+/// Python wrapper for `DateValidation`
 #[pyclass(name = "DateValidation")]
 #[derive(Clone)]
 pub struct PyDateValidation {
@@ -114,7 +122,7 @@ impl PyDateValidation {
     }
 }
 
-/// Python wrapper for TextValidation
+/// Python wrapper for `TextValidation`
 #[pyclass(name = "TextValidation")]
 #[derive(Clone)]
 pub struct PyTextValidation {
@@ -156,7 +164,7 @@ impl PyTextValidation {
     }
 }
 
-/// Python wrapper for ValidationError
+/// Python wrapper for `ValidationError`
 #[pyclass(name = "ValidationError")]
 #[derive(Clone)]
 pub struct PyValidationError {
@@ -168,31 +176,36 @@ impl PyValidationError {
     /// Create a new validation error
     #[new]
     fn new(style: u8) -> Self {
-        let style_enum = match style {
-            0 => ValidationErrorStyle::Stop,
-            1 => ValidationErrorStyle::Warning,
-            2 => ValidationErrorStyle::Information,
-            _ => ValidationErrorStyle::Stop,
-        };
         Self {
-            inner: ValidationError::new(style_enum),
+            inner: ValidationError::new(error_style_from_u8(style)),
         }
     }
 
     /// Set error title
-    fn title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).title(title);
         slf
     }
 
     /// Set error message
-    fn message(mut slf: PyRefMut<'_, Self>, message: &str) -> PyRefMut<'_, Self> {
+    fn message<'py>(mut slf: PyRefMut<'py, Self>, message: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).message(message);
         slf
     }
 }
 
-/// Python wrapper for ValidationWarning
+/// Map a Python integer to a `ValidationErrorStyle`, defaulting to `Stop`
+#[allow(clippy::match_same_arms)]
+fn error_style_from_u8(style: u8) -> ValidationErrorStyle {
+    match style {
+        0 => ValidationErrorStyle::Stop,
+        1 => ValidationErrorStyle::Warning,
+        2 => ValidationErrorStyle::Information,
+        _ => ValidationErrorStyle::Stop,
+    }
+}
+
+/// Python wrapper for `ValidationWarning`
 #[pyclass(name = "ValidationWarning")]
 #[derive(Clone)]
 pub struct PyValidationWarning {
@@ -210,19 +223,19 @@ impl PyValidationWarning {
     }
 
     /// Set warning title
-    fn title(mut slf: PyRefMut<'_, Self>, title: &str) -> PyRefMut<'_, Self> {
+    fn title<'py>(mut slf: PyRefMut<'py, Self>, title: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).title(title);
         slf
     }
 
     /// Set warning message
-    fn message(mut slf: PyRefMut<'_, Self>, message: &str) -> PyRefMut<'_, Self> {
+    fn message<'py>(mut slf: PyRefMut<'py, Self>, message: &str) -> PyRefMut<'py, Self> {
         slf.inner = std::mem::take(&mut slf.inner).message(message);
         slf
     }
 }
 
-/// Python wrapper for DataValidation
+/// Python wrapper for `DataValidation`
 #[pyclass(name = "DataValidation")]
 pub struct PyDataValidation {
     pub(crate) inner: DataValidation,
@@ -271,20 +284,39 @@ impl PyDataValidation {
     }
 
     /// Set error configuration
-    fn error(mut slf: PyRefMut<'_, Self>, error: &PyValidationError) -> PyRefMut<'_, Self> {
-        slf.inner = std::mem::take(&mut slf.inner).error(error.inner.clone());
+    fn error<'py>(mut slf: PyRefMut<'py, Self>, error: &PyValidationError) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().error(error.inner.clone());
         slf
     }
 
     /// Set input warning
-    fn warning(mut slf: PyRefMut<'_, Self>, warning: &PyValidationWarning) -> PyRefMut<'_, Self> {
-        slf.inner = std::mem::take(&mut slf.inner).warning(warning.inner.clone());
+    fn warning<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        warning: &PyValidationWarning,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().warning(warning.inner.clone());
+        slf
+    }
+
+    /// Set the input prompt shown when the cell is selected
+    fn input_message<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        title: &str,
+        body: &str,
+    ) -> PyRefMut<'py, Self> {
+        slf.inner = slf.inner.clone().input_message(title, body);
+        slf
+    }
+
+    /// Set whether to show the input prompt
+    fn show_input_message(mut slf: PyRefMut<'_, Self>, show: bool) -> PyRefMut<'_, Self> {
+        slf.inner = slf.inner.clone().show_input_message(show);
         slf
     }
 
     /// Set whether to ignore blank cells
     fn ignore_blank(mut slf: PyRefMut<'_, Self>, ignore: bool) -> PyRefMut<'_, Self> {
-        slf.inner = std::mem::take(&mut slf.inner).ignore_blank(ignore);
+        slf.inner = slf.inner.clone().ignore_blank(ignore);
         slf
     }
 }
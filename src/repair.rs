@@ -0,0 +1,293 @@
+//! Lenient package repair
+//!
+//! Fixes minor damage in an xlsx package before calamine parses it: a
+//! truncated or malformed shared strings part, cells pointing past the end
+//! of the shared strings, and `<dimension>` elements without a usable range.
+//! Each fix is reported as a [`Warning`].
+
+use crate::compat::utils::coordinate_from_string;
+use crate::error::{Error, Result};
+use crate::package::{element_attributes, rewrite_package, Package};
+use crate::reader::Warning;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader as XmlReader, Writer as XmlWriter};
+use std::collections::BTreeMap;
+use std::io::Cursor;
+use std::path::Path;
+
+/// Part name of the shared strings table
+const SHARED_STRINGS_PART: &str = "xl/sharedStrings.xml";
+
+/// Repair an xlsx package
+///
+/// Returns the repaired package and what was fixed, or the package
+/// unchanged with no warnings if nothing needed fixing.
+///
+/// # Errors
+///
+/// Returns `Error::InvalidFormat` if the data is not a zip package or a
+/// worksheet is damaged beyond repair.
+pub(crate) fn repair_package(data: &[u8]) -> Result<(Vec<u8>, Vec<Warning>)> {
+    let mut package = Package::open(Box::new(Cursor::new(data.to_vec())))?;
+    let mut parts = BTreeMap::new();
+    let mut warnings = Vec::new();
+
+    let mut strings = 0;
+    if let Some(xml) = package.read_text(SHARED_STRINGS_PART)? {
+        let (count, repaired) = repair_shared_strings(&xml, &mut warnings);
+        strings = count;
+        if let Some(repaired) = repaired {
+            parts.insert(SHARED_STRINGS_PART.to_string(), repaired);
+        }
+    }
+
+    for part in package.part_names() {
+        let is_worksheet = part.starts_with("xl/worksheets/")
+            && Path::new(&part)
+                .extension()
+                .is_some_and(|extension| extension.eq_ignore_ascii_case("xml"));
+        if !is_worksheet {
+            continue;
+        }
+        let xml = package.require_text(&part)?;
+        if let Some(repaired) = repair_worksheet(&xml, &part, strings, &mut warnings)? {
+            parts.insert(part, repaired);
+        }
+    }
+
+    if parts.is_empty() {
+        return Ok((data.to_vec(), warnings));
+    }
+    Ok((rewrite_package(data, &parts)?, warnings))
+}
+
+/// Count the complete strings in a shared strings part, rebuilding the part
+/// from them if it is malformed
+fn repair_shared_strings(xml: &str, warnings: &mut Vec<Warning>) -> (usize, Option<String>) {
+    let mut reader = XmlReader::from_str(xml);
+    let mut header_end = None;
+    let mut items = Vec::new();
+    let mut item_start = None;
+    let problem = loop {
+        let position = reader.buffer_position();
+        match reader.read_event() {
+            Ok(Event::Start(e)) if e.local_name().as_ref() == b"sst" => {
+                header_end = Some(reader.buffer_position());
+            }
+            Ok(Event::Start(e)) if e.local_name().as_ref() == b"si" => item_start = Some(position),
+            Ok(Event::Empty(e)) if e.local_name().as_ref() == b"si" => {
+                items.push(&xml[position..reader.buffer_position()]);
+            }
+            Ok(Event::End(e)) if e.local_name().as_ref() == b"si" => {
+                if let Some(start) = item_start.take() {
+                    items.push(&xml[start..reader.buffer_position()]);
+                }
+            }
+            Ok(Event::End(e)) if e.local_name().as_ref() == b"sst" => return (items.len(), None),
+            Ok(Event::Eof) => break "the part ends early".to_string(),
+            Err(e) => break e.to_string(),
+            Ok(_) => {}
+        }
+    };
+
+    warnings.push(Warning::new(
+        SHARED_STRINGS_PART,
+        format!(
+            "malformed shared strings ({problem}); kept the first {}",
+            items.len()
+        ),
+    ));
+    let header = header_end.map_or(
+        "<sst xmlns=\"http://schemas.openxmlformats.org/spreadsheetml/2006/main\">",
+        |end| &xml[..end],
+    );
+    (
+        items.len(),
+        Some(format!("{header}{}</sst>", items.concat())),
+    )
+}
+
+/// Blank out cells with bad shared string indexes and drop unusable
+/// `<dimension>` elements, returning the fixed XML if anything changed
+fn repair_worksheet(
+    xml: &str,
+    part: &str,
+    strings: usize,
+    warnings: &mut Vec<Warning>,
+) -> Result<Option<String>> {
+    let mut reader = XmlReader::from_str(xml);
+    let mut writer = XmlWriter::new(Vec::new());
+    let mut changed = false;
+    let mut in_dimension = false;
+    let mut cell: Option<Vec<Event<'_>>> = None;
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| Error::invalid_format(format!("cannot repair {part}: {e}")))?;
+        match event {
+            Event::Start(ref e) | Event::Empty(ref e)
+                if e.local_name().as_ref() == b"dimension" =>
+            {
+                if has_valid_range(e, &reader) {
+                    write_event(&mut writer, event, part)?;
+                    continue;
+                }
+                in_dimension = matches!(event, Event::Start(_));
+                changed = true;
+                warnings.push(Warning::new(
+                    part,
+                    "dropped a dimension without a valid range",
+                ));
+            }
+            Event::End(ref e) if in_dimension && e.local_name().as_ref() == b"dimension" => {
+                in_dimension = false;
+            }
+            Event::Start(ref e) if e.local_name().as_ref() == b"c" && is_shared_string(e) => {
+                cell = Some(vec![event]);
+            }
+            Event::End(ref e) if cell.is_some() && e.local_name().as_ref() == b"c" => {
+                let mut events = cell.take().unwrap_or_default();
+                events.push(event);
+                match bad_string_index(&events, strings) {
+                    Some(problem) => {
+                        changed = true;
+                        let Event::Start(start) = &events[0] else {
+                            unreachable!("cells are buffered from their start tag")
+                        };
+                        let blank = blank_cell(start);
+                        let reference = element_attributes(start, &reader)
+                            .remove("r")
+                            .unwrap_or_default();
+                        warnings.push(Warning::new(
+                            part,
+                            format!("cell {reference}: {problem}; left blank"),
+                        ));
+                        write_event(&mut writer, Event::Empty(blank), part)?;
+                    }
+                    None => {
+                        for event in events {
+                            write_event(&mut writer, event, part)?;
+                        }
+                    }
+                }
+            }
+            Event::Eof => break,
+            event => match cell.as_mut() {
+                Some(events) => events.push(event),
+                None if in_dimension => {}
+                None => write_event(&mut writer, event, part)?,
+            },
+        }
+    }
+
+    if !changed {
+        return Ok(None);
+    }
+    String::from_utf8(writer.into_inner())
+        .map(Some)
+        .map_err(|_| Error::invalid_format(format!("{part} is not valid UTF-8")))
+}
+
+/// Whether a `<dimension>` has a `ref` like `A1` or `A1:C10`
+fn has_valid_range(element: &BytesStart<'_>, reader: &XmlReader<&[u8]>) -> bool {
+    element_attributes(element, reader)
+        .get("ref")
+        .is_some_and(|range| {
+            range
+                .split(':')
+                .all(|cell| coordinate_from_string(cell).is_ok())
+        })
+}
+
+/// Whether a `<c>` element holds a shared string
+fn is_shared_string(element: &BytesStart<'_>) -> bool {
+    element
+        .attributes()
+        .flatten()
+        .any(|a| a.key.local_name().as_ref() == b"t" && a.value.as_ref() == b"s")
+}
+
+/// Describe what's wrong with a shared string cell's index, if anything
+fn bad_string_index(events: &[Event<'_>], strings: usize) -> Option<String> {
+    let value = events.iter().find_map(|event| match event {
+        Event::Text(text) => text.unescape().ok().map(|text| text.trim().to_string()),
+        _ => None,
+    });
+    match value.as_deref().map(str::parse::<usize>) {
+        Some(Ok(index)) if index < strings => None,
+        Some(Ok(index)) => Some(format!("shared string index {index} is out of range")),
+        Some(Err(_)) | None => Some("shared string index is missing or invalid".to_string()),
+    }
+}
+
+/// Empty copy of a cell's start tag without its type, so it reads as blank
+fn blank_cell(start: &BytesStart<'_>) -> BytesStart<'static> {
+    let mut blank = BytesStart::new("c");
+    blank.extend_attributes(
+        start
+            .attributes()
+            .flatten()
+            .filter(|a| a.key.local_name().as_ref() != b"t"),
+    );
+    blank.into_owned()
+}
+
+/// Write an event, mapping failures to an invalid part
+fn write_event(writer: &mut XmlWriter<Vec<u8>>, event: Event<'_>, part: &str) -> Result<()> {
+    writer
+        .write_event(event)
+        .map_err(|e| Error::invalid_format(format!("cannot repair {part}: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test a truncated shared strings part keeps complete strings
+    #[test]
+    fn test_repair_shared_strings() {
+        let xml = "<sst count=\"3\"><si><t>Name</t></si><si><t>Alice</t></si><si><t>Bo";
+        let mut warnings = Vec::new();
+
+        let (count, repaired) = repair_shared_strings(xml, &mut warnings);
+
+        assert_eq!(count, 2);
+        assert_eq!(
+            repaired.as_deref(),
+            Some("<sst count=\"3\"><si><t>Name</t></si><si><t>Alice</t></si></sst>")
+        );
+        assert_eq!(warnings.len(), 1);
+    }
+
+    /// TDD RED: Test bad string indexes and dimensions are repaired
+    #[test]
+    fn test_repair_worksheet() {
+        let xml = "<worksheet><dimension ref=\"bad\"/><sheetData><row r=\"1\">\
+            <c r=\"A1\" t=\"s\"><v>0</v></c><c r=\"B1\" s=\"1\" t=\"s\"><v>7</v></c>\
+            </row></sheetData></worksheet>";
+        let mut warnings = Vec::new();
+
+        let repaired = repair_worksheet(xml, "sheet1.xml", 1, &mut warnings)
+            .unwrap()
+            .unwrap();
+
+        assert!(!repaired.contains("<dimension"));
+        assert!(repaired.contains("<c r=\"A1\" t=\"s\"><v>0</v></c>"));
+        assert!(repaired.contains("<c r=\"B1\" s=\"1\"/>"), "{repaired}");
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[1].message.contains("B1"));
+    }
+
+    /// TDD RED: Test a well-formed worksheet is left alone
+    #[test]
+    fn test_repair_worksheet_unchanged() {
+        let xml = "<worksheet><dimension ref=\"A1:B2\"/><sheetData/></worksheet>";
+        let mut warnings = Vec::new();
+
+        assert_eq!(
+            repair_worksheet(xml, "sheet1.xml", 0, &mut warnings).unwrap(),
+            None
+        );
+        assert_eq!(warnings, []);
+    }
+}
@@ -0,0 +1,255 @@
+//! Shared formulas
+//!
+//! Excel stores a formula filled across a range once, on the range's first
+//! cell, and marks the other cells with a reference to it. calamine only
+//! returns the formula of that first cell, so the others are rebuilt here by
+//! shifting the formula's relative cell references.
+
+use crate::compat::utils::{column_index_from_string, get_column_letter};
+use crate::error::{Error, Result};
+use crate::package::{element_attributes, Package};
+use quick_xml::events::Event;
+use quick_xml::Reader as XmlReader;
+use std::collections::HashMap;
+
+/// Last row of a worksheet, one-based
+const MAX_ROW: i64 = 1_048_576;
+
+/// Last column of a worksheet, one-based
+const MAX_COL: i64 = 16_384;
+
+/// `<f t="shared">` element of a cell
+struct SharedFormula {
+    /// One-based `(row, col)` of the cell
+    position: (u32, u32),
+    /// Index of the shared group
+    group: String,
+    /// Formula text, present only on the group's first cell
+    formula: String,
+}
+
+/// Read the formulas of cells that share another cell's formula
+///
+/// Keys are zero-based `(row, col)`; the cell holding the group's formula
+/// text is left out. Workbooks that are not xlsx packages have none.
+///
+/// # Errors
+///
+/// Returns `Error::SheetNotFound` if the sheet doesn't exist.
+/// Returns `Error::InvalidFormat` if the worksheet is malformed.
+pub(crate) fn read_shared_formulas(
+    package: &mut Package,
+    sheet: &str,
+) -> Result<HashMap<(u32, u32), String>> {
+    if package.read_text("xl/workbook.xml")?.is_none() {
+        return Ok(HashMap::new());
+    }
+    let sheet_part = package.sheet_part(sheet)?;
+    let worksheet = package.require_text(&sheet_part)?;
+    shared_formulas(&worksheet)
+}
+
+/// Expand the shared formulas of a worksheet's XML
+fn shared_formulas(worksheet: &str) -> Result<HashMap<(u32, u32), String>> {
+    let (masters, dependents): (Vec<_>, Vec<_>) = shared_formula_cells(worksheet)?
+        .into_iter()
+        .partition(|cell| !cell.formula.is_empty());
+    let masters: HashMap<_, _> = masters
+        .into_iter()
+        .map(|master| (master.group.clone(), master))
+        .collect();
+
+    Ok(dependents
+        .into_iter()
+        .filter_map(|cell| {
+            let master = masters.get(&cell.group)?;
+            let (row, col) = cell.position;
+            let formula = shift_references(
+                &master.formula,
+                i64::from(row) - i64::from(master.position.0),
+                i64::from(col) - i64::from(master.position.1),
+            );
+            Some(((row - 1, col - 1), formula))
+        })
+        .collect())
+}
+
+/// Every shared formula element of a worksheet, in document order
+fn shared_formula_cells(worksheet: &str) -> Result<Vec<SharedFormula>> {
+    let mut reader = XmlReader::from_str(worksheet);
+    let mut cells = Vec::new();
+    let mut position = None;
+    let mut in_formula = false;
+    loop {
+        let event = reader.read_event();
+        match event {
+            Ok(Event::Start(ref e)) if e.local_name().as_ref() == b"c" => {
+                position = element_attributes(e, &reader)
+                    .get("r")
+                    .and_then(|r| cell_position(r));
+            }
+            Ok(Event::Start(ref e) | Event::Empty(ref e)) if e.local_name().as_ref() == b"f" => {
+                let mut attrs = element_attributes(e, &reader);
+                let shared = attrs.get("t").map(String::as_str) == Some("shared");
+                if let (true, Some(position), Some(group)) = (shared, position, attrs.remove("si"))
+                {
+                    in_formula = matches!(event, Ok(Event::Start(_)));
+                    cells.push(SharedFormula {
+                        position,
+                        group,
+                        formula: String::new(),
+                    });
+                }
+            }
+            Ok(Event::Text(text)) if in_formula => {
+                let formula = text
+                    .unescape()
+                    .map_err(|e| Error::invalid_format(format!("invalid worksheet XML: {e}")))?;
+                if let Some(cell) = cells.last_mut() {
+                    cell.formula.push_str(&formula);
+                }
+            }
+            Ok(Event::End(ref e)) if e.local_name().as_ref() == b"f" => in_formula = false,
+            Ok(Event::Eof) => return Ok(cells),
+            Err(e) => return Err(Error::invalid_format(format!("invalid worksheet XML: {e}"))),
+            Ok(_) => {}
+        }
+    }
+}
+
+/// One-based `(row, col)` of an A1 cell reference
+fn cell_position(reference: &str) -> Option<(u32, u32)> {
+    let split = reference.find(|c: char| c.is_ascii_digit())?;
+    let (letters, digits) = reference.split_at(split);
+    let col = column_index_from_string(letters).ok()?;
+    Some((digits.parse().ok()?, u32::try_from(col).ok()?))
+}
+
+/// Move the relative cell references of a formula by `rows` and `cols`
+///
+/// Absolute parts (`$A`, `$1`) stay put, as do quoted strings and sheet
+/// names. References moved off the worksheet become `#REF!`.
+fn shift_references(formula: &str, rows: i64, cols: i64) -> String {
+    let mut shifted = String::with_capacity(formula.len());
+    let mut chars = formula.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c == '"' || c == '\'' {
+            let end = formula[start + 1..]
+                .find(c)
+                .map_or(formula.len(), |end| start + end + 2);
+            shifted.push_str(&formula[start..end]);
+            while chars.peek().is_some_and(|(i, _)| *i < end) {
+                chars.next();
+            }
+            continue;
+        }
+        if !is_token_char(c) {
+            shifted.push(c);
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        while let Some((i, next)) = chars.peek().copied() {
+            if !is_token_char(next) {
+                break;
+            }
+            end = i + next.len_utf8();
+            chars.next();
+        }
+        let token = &formula[start..end];
+        let next = formula[end..].chars().next();
+        match shift_reference(token, rows, cols) {
+            Some(reference) if !matches!(next, Some('(' | '!')) => shifted.push_str(&reference),
+            _ => shifted.push_str(token),
+        }
+    }
+    shifted
+}
+
+/// Whether a character can be part of a name, number or cell reference
+fn is_token_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '$' | '.')
+}
+
+/// Shift a token if it is a cell reference like `A1`, `$A1` or `A$1`
+fn shift_reference(token: &str, rows: i64, cols: i64) -> Option<String> {
+    let (col_absolute, rest) = match token.strip_prefix('$') {
+        Some(rest) => (true, rest),
+        None => (false, token),
+    };
+    let letters = rest.find(|c: char| !c.is_ascii_alphabetic())?;
+    let (col, rest) = rest.split_at(letters);
+    let (row_absolute, row) = match rest.strip_prefix('$') {
+        Some(row) => (true, row),
+        None => (false, rest),
+    };
+    if col.is_empty() || col.len() > 3 || row.is_empty() || !row.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let col_index = i64::try_from(column_index_from_string(col).ok()?).ok()?;
+    let row_index: i64 = row.parse().ok()?;
+    if col_index > MAX_COL || !(1..=MAX_ROW).contains(&row_index) {
+        return None;
+    }
+    let new_col = if col_absolute {
+        col_index
+    } else {
+        col_index + cols
+    };
+    let new_row = if row_absolute {
+        row_index
+    } else {
+        row_index + rows
+    };
+    if !(1..=MAX_COL).contains(&new_col) || !(1..=MAX_ROW).contains(&new_row) {
+        return Some("#REF!".to_string());
+    }
+
+    let col_letter = get_column_letter(usize::try_from(new_col).ok()?);
+    Some(format!(
+        "{}{col_letter}{}{new_row}",
+        if col_absolute { "$" } else { "" },
+        if row_absolute { "$" } else { "" },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test relative references move and absolute ones stay
+    #[test]
+    fn test_shift_references() {
+        assert_eq!(shift_references("A1*2", 4, 0), "A5*2");
+        assert_eq!(shift_references("SUM($A$1:A1)", 2, 1), "SUM($A$1:B3)");
+        assert_eq!(shift_references("$A1+A$1", 1, 1), "$A2+B$1");
+        assert_eq!(
+            shift_references("Sheet2!B2*LOG10(C3)", 1, 0),
+            "Sheet2!B3*LOG10(C4)"
+        );
+        assert_eq!(
+            shift_references("'Q1 A1'!A1&\"A1\"", 1, 0),
+            "'Q1 A1'!A2&\"A1\""
+        );
+        assert_eq!(shift_references("A1*1.5E+10", 0, 1), "B1*1.5E+10");
+        assert_eq!(shift_references("A2", -2, 0), "#REF!");
+    }
+
+    /// TDD RED: Test dependent cells get the master formula shifted
+    #[test]
+    fn test_shared_formulas() {
+        let xml = r#"<worksheet><sheetData>
+            <row r="1"><c r="B1"><f t="shared" ref="B1:B3" si="0">A1*2</f><v>2</v></c></row>
+            <row r="2"><c r="B2"><f t="shared" si="0"/><v>4</v></c></row>
+            <row r="3"><c r="B3"><f t="shared" si="0"/><v>6</v></c>
+                <c r="C3"><f>A3+1</f><v>4</v></c></row>
+        </sheetData></worksheet>"#;
+
+        let formulas = shared_formulas(xml).unwrap();
+
+        assert_eq!(formulas.len(), 2);
+        assert_eq!(formulas[&(1, 1)], "A2*2");
+        assert_eq!(formulas[&(2, 1)], "A3*2");
+    }
+}
@@ -0,0 +1,132 @@
+//! Excel sparklines
+//!
+//! Provides types for adding sparklines, the tiny charts Excel draws inside
+//! a single cell, to a worksheet.
+
+use rust_xlsxwriter::{Sparkline, SparklineType};
+
+/// Sparkline chart type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SparklineKind {
+    /// Line sparkline
+    #[default]
+    Line,
+    /// Column (histogram) sparkline
+    Column,
+    /// Win/loss sparkline with equal-height positive and negative bars
+    WinLose,
+}
+
+impl From<SparklineKind> for SparklineType {
+    fn from(kind: SparklineKind) -> Self {
+        match kind {
+            SparklineKind::Line => SparklineType::Line,
+            SparklineKind::Column => SparklineType::Column,
+            SparklineKind::WinLose => SparklineType::WinLose,
+        }
+    }
+}
+
+/// Sparkline point highlighting
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use xlsxpress::sparkline::SparklineOptions;
+///
+/// let options = SparklineOptions::new().high_point(true).low_point(true);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SparklineOptions {
+    /// Highlight the highest point
+    high_point: bool,
+    /// Highlight the lowest point
+    low_point: bool,
+    /// Show a marker on every point (line sparklines only)
+    markers: bool,
+}
+
+impl SparklineOptions {
+    /// Create new sparkline options with no highlighting
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether to highlight the highest point
+    #[must_use]
+    pub fn high_point(mut self, enable: bool) -> Self {
+        self.high_point = enable;
+        self
+    }
+
+    /// Set whether to highlight the lowest point
+    #[must_use]
+    pub fn low_point(mut self, enable: bool) -> Self {
+        self.low_point = enable;
+        self
+    }
+
+    /// Set whether to show a marker on every point
+    #[must_use]
+    pub fn markers(mut self, enable: bool) -> Self {
+        self.markers = enable;
+        self
+    }
+
+    /// Check if the highest point is highlighted
+    #[must_use]
+    pub fn has_high_point(&self) -> bool {
+        self.high_point
+    }
+
+    /// Check if the lowest point is highlighted
+    #[must_use]
+    pub fn has_low_point(&self) -> bool {
+        self.low_point
+    }
+
+    /// Check if markers are shown
+    #[must_use]
+    pub fn has_markers(&self) -> bool {
+        self.markers
+    }
+
+    /// Convert to a `rust_xlsxwriter` sparkline over a sheet-qualified range
+    pub(crate) fn to_xlsx_sparkline(self, data_range: &str, kind: SparklineKind) -> Sparkline {
+        Sparkline::new()
+            .set_range(data_range)
+            .set_type(kind.into())
+            .show_high_point(self.high_point)
+            .show_low_point(self.low_point)
+            .show_markers(self.markers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test sparkline options builder
+    #[test]
+    fn test_sparkline_options_builder() {
+        let options = SparklineOptions::new();
+        assert!(!options.has_high_point());
+        assert!(!options.has_low_point());
+        assert!(!options.has_markers());
+
+        let options = SparklineOptions::new()
+            .high_point(true)
+            .low_point(true)
+            .markers(true);
+        assert!(options.has_high_point());
+        assert!(options.has_low_point());
+        assert!(options.has_markers());
+    }
+
+    /// TDD RED: Test sparkline kind defaults to line
+    #[test]
+    fn test_sparkline_kind_default() {
+        assert_eq!(SparklineKind::default(), SparklineKind::Line);
+    }
+}
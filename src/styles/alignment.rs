@@ -5,8 +5,14 @@
 
 use rust_xlsxwriter::{Format, FormatAlign};
 
+/// Font scheme marking formats that justify the last line
+///
+/// `rust_xlsxwriter` has no setter for `justifyLastLine`, so the writer
+/// replaces this marker with the attribute when it saves the workbook.
+pub(crate) const JUSTIFY_LAST_LINE_SCHEME: &str = "xlsxpress-justifyLastLine";
+
 /// Horizontal alignment types
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum HorizontalAlignment {
     /// General alignment (default)
     General,
@@ -42,7 +48,7 @@ impl From<HorizontalAlignment> for FormatAlign {
 }
 
 /// Vertical alignment types
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum VerticalAlignment {
     /// Top aligned
     Top,
@@ -68,6 +74,27 @@ impl From<VerticalAlignment> for FormatAlign {
     }
 }
 
+/// Text reading order for bidirectional text
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReadingOrder {
+    /// Determined by the first strong character in the cell
+    Context,
+    /// Left-to-right
+    LeftToRight,
+    /// Right-to-left
+    RightToLeft,
+}
+
+impl From<ReadingOrder> for u8 {
+    fn from(order: ReadingOrder) -> Self {
+        match order {
+            ReadingOrder::Context => 0,
+            ReadingOrder::LeftToRight => 1,
+            ReadingOrder::RightToLeft => 2,
+        }
+    }
+}
+
 /// Alignment configuration for cell styling
 ///
 /// Configures text alignment, wrapping, rotation, and indentation in cells.
@@ -86,7 +113,8 @@ impl From<VerticalAlignment> for FormatAlign {
 /// let align = Alignment::new()
 ///     .wrap_text(true);
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Alignment {
     /// Horizontal alignment
     horizontal: Option<HorizontalAlignment>,
@@ -96,10 +124,16 @@ pub struct Alignment {
     wrap_text: bool,
     /// Text rotation (0-360 degrees)
     rotation: Option<u16>,
+    /// Stacked vertical text, one letter below another
+    vertical_text: bool,
     /// Indentation level
     indent: Option<u8>,
     /// Shrink to fit
     shrink_to_fit: bool,
+    /// Reading order
+    reading_order: Option<ReadingOrder>,
+    /// Justify the last line of distributed text
+    justify_last_line: bool,
 }
 
 impl Alignment {
@@ -111,8 +145,11 @@ impl Alignment {
             vertical: None,
             wrap_text: false,
             rotation: None,
+            vertical_text: false,
             indent: None,
             shrink_to_fit: false,
+            reading_order: None,
+            justify_last_line: false,
         }
     }
 
@@ -149,6 +186,17 @@ impl Alignment {
         self
     }
 
+    /// Set stacked vertical text
+    ///
+    /// Letters are stacked top to bottom without turning them, which Excel
+    /// stores as the special rotation 255. Takes precedence over
+    /// [`Alignment::rotation`].
+    #[must_use]
+    pub fn vertical_text(mut self, vertical: bool) -> Self {
+        self.vertical_text = vertical;
+        self
+    }
+
     /// Set indentation level (0-15)
     ///
     /// # Arguments
@@ -168,6 +216,22 @@ impl Alignment {
         self
     }
 
+    /// Set reading order
+    #[must_use]
+    pub fn reading_order(mut self, order: ReadingOrder) -> Self {
+        self.reading_order = Some(order);
+        self
+    }
+
+    /// Set justify last line for distributed text
+    ///
+    /// Written as `justifyLastLine` when the workbook is saved.
+    #[must_use]
+    pub fn justify_last_line(mut self, justify: bool) -> Self {
+        self.justify_last_line = justify;
+        self
+    }
+
     /// Apply alignment settings to a `rust_xlsxwriter` Format
     ///
     /// # Arguments
@@ -195,8 +259,10 @@ impl Alignment {
             format = format.set_text_wrap();
         }
 
-        // Set rotation
-        if let Some(rotation) = self.rotation {
+        // Set rotation; rust_xlsxwriter writes 270 as stacked text (255)
+        if self.vertical_text {
+            format = format.set_rotation(270);
+        } else if let Some(rotation) = self.rotation {
             format = format.set_rotation(rotation as i16);
         }
 
@@ -210,6 +276,16 @@ impl Alignment {
             format = format.set_shrink();
         }
 
+        // Set reading order
+        if let Some(order) = self.reading_order {
+            format = format.set_reading_direction(order.into());
+        }
+
+        // Mark justify last line, swapped for the attribute on save
+        if self.justify_last_line {
+            format = format.set_font_scheme(JUSTIFY_LAST_LINE_SCHEME);
+        }
+
         format
     }
 
@@ -237,6 +313,12 @@ impl Alignment {
         self.rotation
     }
 
+    /// Check if text is stacked vertically
+    #[must_use]
+    pub fn is_vertical_text(&self) -> bool {
+        self.vertical_text
+    }
+
     /// Get indentation level
     #[must_use]
     pub fn get_indent(&self) -> Option<u8> {
@@ -248,6 +330,18 @@ impl Alignment {
     pub fn is_shrink_to_fit(&self) -> bool {
         self.shrink_to_fit
     }
+
+    /// Get reading order
+    #[must_use]
+    pub fn get_reading_order(&self) -> Option<ReadingOrder> {
+        self.reading_order
+    }
+
+    /// Check if justify last line is enabled
+    #[must_use]
+    pub fn is_justify_last_line(&self) -> bool {
+        self.justify_last_line
+    }
 }
 
 impl Default for Alignment {
@@ -409,4 +503,40 @@ mod tests {
             // Just verify it compiles and converts
         }
     }
+
+    /// TDD RED: Test reading order
+    #[test]
+    fn test_reading_order() {
+        let align = Alignment::new().reading_order(ReadingOrder::RightToLeft);
+        assert_eq!(align.get_reading_order(), Some(ReadingOrder::RightToLeft));
+        assert_eq!(Alignment::new().get_reading_order(), None);
+
+        let format = align.apply_to_format(Format::new());
+        assert_eq!(format, Format::new().set_reading_direction(2));
+    }
+
+    /// TDD RED: Test stacked vertical text
+    #[test]
+    fn test_vertical_text() {
+        let align = Alignment::new().rotation(45).vertical_text(true);
+        assert!(align.is_vertical_text());
+        assert!(!Alignment::new().is_vertical_text());
+        assert_eq!(align.get_rotation(), Some(45));
+
+        // Stacked text wins over the numeric rotation
+        let format = align.apply_to_format(Format::new());
+        assert_eq!(format, Format::new().set_rotation(270));
+        let format = align.vertical_text(false).apply_to_format(Format::new());
+        assert_eq!(format, Format::new().set_rotation(45));
+    }
+
+    /// TDD RED: Test justify last line
+    #[test]
+    fn test_justify_last_line() {
+        let align = Alignment::new()
+            .horizontal(HorizontalAlignment::Distributed)
+            .justify_last_line(true);
+        assert!(align.is_justify_last_line());
+        assert!(!Alignment::new().is_justify_last_line());
+    }
 }
@@ -3,10 +3,10 @@
 //! Provides Border type for configuring cell borders including styles,
 //! colors, and individual edge configuration.
 
-use rust_xlsxwriter::{Color, Format, FormatBorder};
+use rust_xlsxwriter::{Color, Format, FormatBorder, FormatDiagonalBorder};
 
 /// Border style types
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum BorderStyle {
     /// No border
     None,
@@ -59,6 +59,27 @@ impl From<BorderStyle> for FormatBorder {
     }
 }
 
+/// Diagonal border directions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagonalType {
+    /// Bottom-left to top-right
+    Up,
+    /// Top-left to bottom-right
+    Down,
+    /// Both diagonals
+    Both,
+}
+
+impl From<DiagonalType> for FormatDiagonalBorder {
+    fn from(diagonal_type: DiagonalType) -> Self {
+        match diagonal_type {
+            DiagonalType::Up => FormatDiagonalBorder::BorderUp,
+            DiagonalType::Down => FormatDiagonalBorder::BorderDown,
+            DiagonalType::Both => FormatDiagonalBorder::BorderUpDown,
+        }
+    }
+}
+
 /// Border configuration for cell styling
 ///
 /// Configures cell borders for individual edges or all edges at once.
@@ -76,7 +97,7 @@ impl From<BorderStyle> for FormatBorder {
 ///     .top(BorderStyle::Thick)
 ///     .bottom(BorderStyle::Thick);
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Border {
     /// Top border style
     top: BorderStyle,
@@ -90,8 +111,16 @@ pub struct Border {
     diagonal_up: BorderStyle,
     /// Diagonal down border style
     diagonal_down: BorderStyle,
-    /// Border color
-    color: Option<Color>,
+    /// Top border color
+    top_color: Option<Color>,
+    /// Bottom border color
+    bottom_color: Option<Color>,
+    /// Left border color
+    left_color: Option<Color>,
+    /// Right border color
+    right_color: Option<Color>,
+    /// Diagonal border color
+    diagonal_color: Option<Color>,
 }
 
 impl Border {
@@ -105,7 +134,11 @@ impl Border {
             right: BorderStyle::None,
             diagonal_up: BorderStyle::None,
             diagonal_down: BorderStyle::None,
-            color: None,
+            top_color: None,
+            bottom_color: None,
+            left_color: None,
+            right_color: None,
+            diagonal_color: None,
         }
     }
 
@@ -129,7 +162,11 @@ impl Border {
             right: style,
             diagonal_up: BorderStyle::None,
             diagonal_down: BorderStyle::None,
-            color: None,
+            top_color: None,
+            bottom_color: None,
+            left_color: None,
+            right_color: None,
+            diagonal_color: None,
         }
     }
 
@@ -147,7 +184,11 @@ impl Border {
             right: style,
             diagonal_up: BorderStyle::None,
             diagonal_down: BorderStyle::None,
-            color: None,
+            top_color: None,
+            bottom_color: None,
+            left_color: None,
+            right_color: None,
+            diagonal_color: None,
         }
     }
 
@@ -193,22 +234,37 @@ impl Border {
         self
     }
 
-    /// Set border color from hex string
+    /// Set diagonal border style for one or both directions
     ///
     /// # Arguments
     ///
-    /// * `color` - Hex color string like "#000000" or "000000"
+    /// * `style` - Diagonal border style
+    /// * `diagonal_type` - Which diagonals to draw
     #[must_use]
-    pub fn color(mut self, color: impl Into<String>) -> Self {
-        let color_str = color.into();
-        let color_str = color_str.trim_start_matches('#');
-        if let Ok(parsed) = u32::from_str_radix(color_str, 16) {
-            self.color = Some(Color::RGB(parsed));
+    pub fn diagonal(mut self, style: BorderStyle, diagonal_type: DiagonalType) -> Self {
+        if diagonal_type != DiagonalType::Down {
+            self.diagonal_up = style;
+        }
+        if diagonal_type != DiagonalType::Up {
+            self.diagonal_down = style;
         }
         self
     }
 
-    /// Set border color from RGB values
+    /// Set the color of all edges from hex string
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Hex color string like "#000000" or "000000"
+    #[must_use]
+    pub fn color(self, color: impl Into<String>) -> Self {
+        match parse_hex_color(&color.into()) {
+            Some(color) => self.all_colors(color),
+            None => self,
+        }
+    }
+
+    /// Set the color of all edges from RGB values
     ///
     /// # Arguments
     ///
@@ -216,9 +272,53 @@ impl Border {
     /// * `g` - Green component (0-255)
     /// * `b` - Blue component (0-255)
     #[must_use]
-    pub fn rgb(mut self, r: u8, g: u8, b: u8) -> Self {
+    pub fn rgb(self, r: u8, g: u8, b: u8) -> Self {
         let rgb_value = u32::from(r) << 16 | u32::from(g) << 8 | u32::from(b);
-        self.color = Some(Color::RGB(rgb_value));
+        self.all_colors(Color::RGB(rgb_value))
+    }
+
+    /// Set top border color from hex string
+    #[must_use]
+    pub fn top_color(mut self, color: impl Into<String>) -> Self {
+        self.top_color = parse_hex_color(&color.into()).or(self.top_color);
+        self
+    }
+
+    /// Set bottom border color from hex string
+    #[must_use]
+    pub fn bottom_color(mut self, color: impl Into<String>) -> Self {
+        self.bottom_color = parse_hex_color(&color.into()).or(self.bottom_color);
+        self
+    }
+
+    /// Set left border color from hex string
+    #[must_use]
+    pub fn left_color(mut self, color: impl Into<String>) -> Self {
+        self.left_color = parse_hex_color(&color.into()).or(self.left_color);
+        self
+    }
+
+    /// Set right border color from hex string
+    #[must_use]
+    pub fn right_color(mut self, color: impl Into<String>) -> Self {
+        self.right_color = parse_hex_color(&color.into()).or(self.right_color);
+        self
+    }
+
+    /// Set diagonal border color from hex string
+    #[must_use]
+    pub fn diagonal_color(mut self, color: impl Into<String>) -> Self {
+        self.diagonal_color = parse_hex_color(&color.into()).or(self.diagonal_color);
+        self
+    }
+
+    /// Set every edge to the same color
+    fn all_colors(mut self, color: Color) -> Self {
+        self.top_color = Some(color);
+        self.bottom_color = Some(color);
+        self.left_color = Some(color);
+        self.right_color = Some(color);
+        self.diagonal_color = Some(color);
         self
     }
 
@@ -246,17 +346,34 @@ impl Border {
         if self.right != BorderStyle::None {
             format = format.set_border_right(self.right.into());
         }
-        // Apply diagonal borders - rust_xlsxwriter has a single diagonal method
-        // If either diagonal is set, use that style
-        if self.diagonal_up != BorderStyle::None {
-            format = format.set_border_diagonal(self.diagonal_up.into());
-        } else if self.diagonal_down != BorderStyle::None {
-            format = format.set_border_diagonal(self.diagonal_down.into());
+        // Apply diagonal borders - rust_xlsxwriter has a single diagonal style,
+        // so the up style wins when both directions are set
+        if let Some(diagonal_type) = self.get_diagonal_type() {
+            let style = if self.diagonal_up == BorderStyle::None {
+                self.diagonal_down
+            } else {
+                self.diagonal_up
+            };
+            format = format
+                .set_border_diagonal(style.into())
+                .set_border_diagonal_type(diagonal_type.into());
         }
 
-        // Set border color if specified
-        if let Some(color) = self.color {
-            format = format.set_border_color(color);
+        // Set edge colors if specified
+        if let Some(color) = self.top_color {
+            format = format.set_border_top_color(color);
+        }
+        if let Some(color) = self.bottom_color {
+            format = format.set_border_bottom_color(color);
+        }
+        if let Some(color) = self.left_color {
+            format = format.set_border_left_color(color);
+        }
+        if let Some(color) = self.right_color {
+            format = format.set_border_right_color(color);
+        }
+        if let Some(color) = self.diagonal_color {
+            format = format.set_border_diagonal_color(color);
         }
 
         format
@@ -285,6 +402,27 @@ impl Border {
     pub fn get_right(&self) -> BorderStyle {
         self.right
     }
+
+    /// Get diagonal directions, or `None` if no diagonal is set
+    #[must_use]
+    pub fn get_diagonal_type(&self) -> Option<DiagonalType> {
+        match (
+            self.diagonal_up != BorderStyle::None,
+            self.diagonal_down != BorderStyle::None,
+        ) {
+            (true, true) => Some(DiagonalType::Both),
+            (true, false) => Some(DiagonalType::Up),
+            (false, true) => Some(DiagonalType::Down),
+            (false, false) => None,
+        }
+    }
+}
+
+/// Parse a hex color string like "#FF0000" or "FF0000"
+fn parse_hex_color(color: &str) -> Option<Color> {
+    u32::from_str_radix(color.trim_start_matches('#'), 16)
+        .ok()
+        .map(Color::RGB)
 }
 
 impl Default for Border {
@@ -344,14 +482,40 @@ mod tests {
     #[test]
     fn test_border_color() {
         let border = Border::all(BorderStyle::Thin).color("#FF0000");
-        assert!(border.color.is_some());
+        assert_eq!(border.top_color, Some(Color::RGB(0xFF_0000)));
+        assert_eq!(border.bottom_color, Some(Color::RGB(0xFF_0000)));
+        assert_eq!(border.left_color, Some(Color::RGB(0xFF_0000)));
+        assert_eq!(border.right_color, Some(Color::RGB(0xFF_0000)));
+        assert_eq!(border.diagonal_color, Some(Color::RGB(0xFF_0000)));
     }
 
     /// TDD RED: Test border with RGB color
     #[test]
     fn test_border_rgb() {
         let border = Border::all(BorderStyle::Thin).rgb(0, 0, 255);
-        assert!(border.color.is_some());
+        assert_eq!(border.top_color, Some(Color::RGB(0x00_00FF)));
+    }
+
+    /// TDD RED: Test distinct top and bottom colors
+    #[test]
+    fn test_border_edge_colors() {
+        let border = Border::new()
+            .top(BorderStyle::Thin)
+            .bottom(BorderStyle::Thin)
+            .top_color("#FF0000")
+            .bottom_color("#0000FF");
+
+        assert_eq!(border.top_color, Some(Color::RGB(0xFF_0000)));
+        assert_eq!(border.bottom_color, Some(Color::RGB(0x00_00FF)));
+        assert_eq!(border.left_color, None);
+
+        let format = border.apply_to_format(Format::new());
+        let expected = Format::new()
+            .set_border_top(FormatBorder::Thin)
+            .set_border_bottom(FormatBorder::Thin)
+            .set_border_top_color(Color::RGB(0xFF_0000))
+            .set_border_bottom_color(Color::RGB(0x00_00FF));
+        assert_eq!(format, expected);
     }
 
     /// TDD RED: Test border builder pattern
@@ -368,7 +532,7 @@ mod tests {
         assert_eq!(border.get_bottom(), BorderStyle::Thick);
         assert_eq!(border.get_left(), BorderStyle::Thin);
         assert_eq!(border.get_right(), BorderStyle::Thin);
-        assert!(border.color.is_some());
+        assert_eq!(border.top_color, Some(Color::RGB(0)));
     }
 
     /// TDD RED: Test diagonal borders
@@ -380,6 +544,25 @@ mod tests {
 
         assert_eq!(border.diagonal_up, BorderStyle::Thin);
         assert_eq!(border.diagonal_down, BorderStyle::Thin);
+        assert_eq!(border.get_diagonal_type(), Some(DiagonalType::Both));
+    }
+
+    /// TDD RED: Test diagonal direction is applied to the format
+    #[test]
+    fn test_border_diagonal_type() {
+        let up = Border::new().diagonal(BorderStyle::Thin, DiagonalType::Up);
+        assert_eq!(up.get_diagonal_type(), Some(DiagonalType::Up));
+
+        let down = Border::new().diagonal_down(BorderStyle::Dashed);
+        assert_eq!(down.get_diagonal_type(), Some(DiagonalType::Down));
+        assert_eq!(Border::new().get_diagonal_type(), None);
+
+        let both = Border::new().diagonal(BorderStyle::Thin, DiagonalType::Both);
+        let format = both.apply_to_format(Format::new());
+        let expected = Format::new()
+            .set_border_diagonal(FormatBorder::Thin)
+            .set_border_diagonal_type(FormatDiagonalBorder::BorderUpDown);
+        assert_eq!(format, expected);
     }
 
     /// TDD RED: Test default trait
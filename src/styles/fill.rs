@@ -5,7 +5,7 @@
 use rust_xlsxwriter::{Color, Format, FormatPattern};
 
 /// Fill pattern types for cell backgrounds
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FillPattern {
     /// Solid fill (most common)
     Solid,
@@ -49,7 +49,7 @@ impl From<FillPattern> for FormatPattern {
 /// // Pattern fill
 /// let fill = Fill::pattern(FillPattern::LightGray);
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Fill {
     /// Fill pattern type
     pattern: FillPattern,
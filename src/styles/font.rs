@@ -3,7 +3,38 @@
 //! Provides Font type for configuring cell text appearance including
 //! font family, size, bold, italic, and color.
 
-use rust_xlsxwriter::{Color, Format};
+use rust_xlsxwriter::{Color, Format, ShapeFont};
+use std::hash::{Hash, Hasher};
+
+/// Font family classes used by Excel for font matching
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FontFamily {
+    /// Family not applicable
+    NotApplicable,
+    /// Proportional serif font (e.g., Times New Roman)
+    Roman,
+    /// Proportional sans-serif font (e.g., Arial)
+    Swiss,
+    /// Monospace font (e.g., Courier New)
+    Modern,
+    /// Script font
+    Script,
+    /// Decorative font
+    Decorative,
+}
+
+impl From<FontFamily> for u8 {
+    fn from(family: FontFamily) -> Self {
+        match family {
+            FontFamily::NotApplicable => 0,
+            FontFamily::Roman => 1,
+            FontFamily::Swiss => 2,
+            FontFamily::Modern => 3,
+            FontFamily::Script => 4,
+            FontFamily::Decorative => 5,
+        }
+    }
+}
 
 /// Font configuration for cell styling
 ///
@@ -21,7 +52,7 @@ use rust_xlsxwriter::{Color, Format};
 ///     .bold(true)
 ///     .color("#FF0000");
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct Font {
     /// Font name (e.g., "Arial", "Calibri")
     name: Option<String>,
@@ -33,6 +64,10 @@ pub struct Font {
     italic: bool,
     /// Text color
     color: Option<Color>,
+    /// Font family class
+    family: Option<FontFamily>,
+    /// Font character set
+    charset: Option<u8>,
 }
 
 impl Font {
@@ -45,6 +80,8 @@ impl Font {
             bold: false,
             italic: false,
             color: None,
+            family: None,
+            charset: None,
         }
     }
 
@@ -76,14 +113,35 @@ impl Font {
         self
     }
 
+    /// Set font family class
+    #[must_use]
+    pub fn family(mut self, family: FontFamily) -> Self {
+        self.family = Some(family);
+        self
+    }
+
+    /// Set font character set (e.g., 0 for ANSI, 128 for Shift JIS)
+    #[must_use]
+    pub fn charset(mut self, charset: u8) -> Self {
+        self.charset = Some(charset);
+        self
+    }
+
     /// Set text color from hex string
     ///
+    /// The literal `"auto"` selects Excel's automatic color, which follows
+    /// the viewer's system settings (e.g. dark mode).
+    ///
     /// # Arguments
     ///
-    /// * `color` - Hex color string like "#FF0000" or "FF0000"
+    /// * `color` - Hex color string like "#FF0000" or "FF0000", or "auto"
     #[must_use]
     pub fn color(mut self, color: impl Into<String>) -> Self {
         let color_str = color.into();
+        if color_str.eq_ignore_ascii_case("auto") {
+            self.color = Some(Color::Automatic);
+            return self;
+        }
         let color_str = color_str.trim_start_matches('#');
         if let Ok(parsed) = u32::from_str_radix(color_str, 16) {
             self.color = Some(Color::RGB(parsed));
@@ -131,9 +189,39 @@ impl Font {
         if let Some(color) = self.color {
             format = format.set_font_color(color);
         }
+        if let Some(family) = self.family {
+            format = format.set_font_family(family.into());
+        }
+        if let Some(charset) = self.charset {
+            format = format.set_font_charset(charset);
+        }
         format
     }
 
+    /// Convert to a `rust_xlsxwriter` font for shapes such as textboxes
+    pub(crate) fn to_shape_font(&self) -> ShapeFont {
+        let mut font = ShapeFont::new();
+        if let Some(ref name) = self.name {
+            font = font.set_name(name);
+        }
+        if let Some(size) = self.size {
+            font = font.set_size(size);
+        }
+        if self.bold {
+            font = font.set_bold();
+        }
+        if self.italic {
+            font = font.set_italic();
+        }
+        if let Some(color) = self.color {
+            font = font.set_color(color);
+        }
+        if let Some(charset) = self.charset {
+            font = font.set_character_set(charset);
+        }
+        font
+    }
+
     /// Get font name
     #[must_use]
     pub fn get_name(&self) -> Option<&str> {
@@ -146,6 +234,18 @@ impl Font {
         self.size
     }
 
+    /// Get font family class
+    #[must_use]
+    pub fn get_family(&self) -> Option<FontFamily> {
+        self.family
+    }
+
+    /// Get font character set
+    #[must_use]
+    pub fn get_charset(&self) -> Option<u8> {
+        self.charset
+    }
+
     /// Check if bold
     #[must_use]
     pub fn is_bold(&self) -> bool {
@@ -165,10 +265,81 @@ impl Default for Font {
     }
 }
 
+/// Compares sizes by their normalized bit pattern, matching `Hash`
+///
+/// `-0.0` equals `0.0` and every NaN size equals every other, so `Font` is a
+/// total equivalence and can be `Eq`.
+impl PartialEq for Font {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.size.map(normalized_bits) == other.size.map(normalized_bits)
+            && self.bold == other.bold
+            && self.italic == other.italic
+            && self.color == other.color
+            && self.family == other.family
+            && self.charset == other.charset
+    }
+}
+
+impl Eq for Font {}
+
+/// Hashes the size by its bit pattern, so styles can key a cache
+///
+/// `-0.0` is hashed as `0.0` since the two compare equal, and every NaN
+/// size is normalized to the same hash.
+impl Hash for Font {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.size.map(normalized_bits).hash(state);
+        self.bold.hash(state);
+        self.italic.hash(state);
+        self.color.hash(state);
+        self.family.hash(state);
+        self.charset.hash(state);
+    }
+}
+
+/// Bit pattern of a float with `-0.0` and NaN normalized
+fn normalized_bits(value: f64) -> u64 {
+    if value.is_nan() {
+        f64::NAN.to_bits()
+    } else if value == 0.0 {
+        0.0_f64.to_bits()
+    } else {
+        value.to_bits()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// TDD RED: Test equal sizes hash equally, including signed zero and NaN
+    #[test]
+    fn test_font_hash_size() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let hash = |font: &Font| {
+            let mut hasher = DefaultHasher::new();
+            font.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&Font::new().size(0.0)), hash(&Font::new().size(-0.0)));
+        assert_eq!(
+            hash(&Font::new().size(f64::NAN)),
+            hash(&Font::new().size(-f64::NAN))
+        );
+        assert_ne!(hash(&Font::new().size(11.0)), hash(&Font::new().size(12.0)));
+    }
+
+    /// TDD RED: Test sizes compare the same way they hash
+    #[test]
+    fn test_font_eq_size() {
+        assert_eq!(Font::new().size(0.0), Font::new().size(-0.0));
+        assert_eq!(Font::new().size(f64::NAN), Font::new().size(f64::NAN));
+        assert_ne!(Font::new().size(11.0), Font::new().size(12.0));
+    }
+
     /// TDD RED: Test font creation with default values
     #[test]
     fn test_font_new() {
@@ -223,6 +394,29 @@ mod tests {
         assert!(font.color.is_some());
     }
 
+    /// TDD RED: Test automatic font color
+    #[test]
+    fn test_font_color_auto() {
+        let font = Font::new().color("auto");
+        assert_eq!(font.color, Some(Color::Automatic));
+
+        let font = Font::new().color("AUTO");
+        assert_eq!(font.color, Some(Color::Automatic));
+
+        // Hex parsing is unaffected
+        let font = Font::new().color("#00AAFF");
+        assert_eq!(font.color, Some(Color::RGB(0x0000_AAFF)));
+
+        // Automatic color can be written out
+        let format = Font::new().color("auto").apply_to_format(Format::new());
+        let mut workbook = rust_xlsxwriter::Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet
+            .write_string_with_format(0, 0, "auto", &format)
+            .unwrap();
+        assert!(workbook.save_to_buffer().is_ok());
+    }
+
     /// TDD RED: Test font color from RGB
     #[test]
     fn test_font_color_rgb() {
@@ -247,6 +441,39 @@ mod tests {
         assert!(font.color.is_some());
     }
 
+    /// TDD RED: Test Swiss-family Arial font
+    #[test]
+    fn test_font_family_and_charset() {
+        let font = Font::new()
+            .name("Arial")
+            .family(FontFamily::Swiss)
+            .charset(0);
+
+        assert_eq!(font.get_name(), Some("Arial"));
+        assert_eq!(font.get_family(), Some(FontFamily::Swiss));
+        assert_eq!(font.get_charset(), Some(0));
+        assert_eq!(u8::from(FontFamily::Swiss), 2);
+
+        // Swiss/ANSI match the backend defaults for Arial
+        let format = font.apply_to_format(Format::new());
+        assert_eq!(format, Format::new().set_font_name("Arial"));
+    }
+
+    /// TDD RED: Test non-default family and charset reach the format
+    #[test]
+    fn test_font_family_applied_to_format() {
+        let format = Font::new()
+            .family(FontFamily::Modern)
+            .charset(128)
+            .apply_to_format(Format::new());
+
+        assert_ne!(format, Format::new());
+        assert_eq!(
+            format,
+            Format::new().set_font_family(3).set_font_charset(128)
+        );
+    }
+
     /// TDD RED: Test default trait
     #[test]
     fn test_font_default() {
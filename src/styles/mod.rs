@@ -11,9 +11,9 @@ pub mod number_format;
 pub mod style;
 
 // Re-export for convenience
-pub use alignment::{Alignment, HorizontalAlignment, VerticalAlignment};
-pub use border::{Border, BorderStyle};
+pub use alignment::{Alignment, HorizontalAlignment, ReadingOrder, VerticalAlignment};
+pub use border::{Border, BorderStyle, DiagonalType};
 pub use fill::{Fill, FillPattern};
-pub use font::Font;
+pub use font::{Font, FontFamily};
 pub use number_format::{NumberFormat, NumberFormatType};
 pub use style::Style;
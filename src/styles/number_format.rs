@@ -6,7 +6,7 @@
 use rust_xlsxwriter::Format;
 
 /// Predefined number format types
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum NumberFormatType {
     /// General format (default)
     General,
@@ -51,7 +51,7 @@ pub enum NumberFormatType {
 /// // Custom format
 /// let fmt = NumberFormat::custom("0.00%");
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct NumberFormat {
     /// Format type
     format_type: NumberFormatType,
@@ -226,8 +226,50 @@ impl NumberFormat {
         }
     }
 
+    /// Create a number format from an Excel format string
+    ///
+    /// Strings produced by the predefined formats, such as `"$#,##0.00"` or
+    /// `"0.0%"`, are recognized with their type and decimal places; any
+    /// other string becomes a custom format. Used to rebuild formats read
+    /// from existing workbooks.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Excel format string
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let fmt = NumberFormat::from_format_string("$#,##0.00");
+    /// assert_eq!(fmt, NumberFormat::currency(2));
+    /// ```
+    #[must_use]
+    pub fn from_format_string(format: &str) -> Self {
+        let fixed = [
+            Self::general(),
+            Self::date(),
+            Self::time(),
+            Self::fraction(),
+            Self::text(),
+        ];
+        let with_decimals = (0..=30).flat_map(|decimals| {
+            [
+                Self::number(decimals),
+                Self::currency(decimals),
+                Self::accounting(decimals),
+                Self::percentage(decimals),
+                Self::scientific(decimals),
+            ]
+        });
+        fixed
+            .into_iter()
+            .chain(with_decimals)
+            .find(|candidate| candidate.get_format_string() == format)
+            .unwrap_or_else(|| Self::custom(format))
+    }
+
     /// Get the format string for this number format
-    fn get_format_string(&self) -> String {
+    pub(crate) fn get_format_string(&self) -> String {
         match &self.format_type {
             NumberFormatType::General => String::from("General"),
             NumberFormatType::Number => {
@@ -439,6 +481,37 @@ mod tests {
         assert_eq!(fmt.get_format_string(), "$#,##0.00_);[Red]($#,##0.00)");
     }
 
+    /// TDD RED: Test format strings map back to predefined formats
+    #[test]
+    fn test_from_format_string() {
+        assert_eq!(
+            NumberFormat::from_format_string("$#,##0.00"),
+            NumberFormat::currency(2)
+        );
+        assert_eq!(
+            NumberFormat::from_format_string("0.0%"),
+            NumberFormat::percentage(1)
+        );
+        assert_eq!(
+            NumberFormat::from_format_string("General"),
+            NumberFormat::general()
+        );
+        assert_eq!(
+            NumberFormat::from_format_string("yyyy-mm-dd"),
+            NumberFormat::date()
+        );
+        assert_eq!(
+            NumberFormat::from_format_string("mmm yy"),
+            NumberFormat::custom("mmm yy")
+        );
+
+        let accounting = NumberFormat::accounting(2);
+        assert_eq!(
+            NumberFormat::from_format_string(&accounting.get_format_string()),
+            accounting
+        );
+    }
+
     /// TDD RED: Test decimal clamping
     #[test]
     fn test_decimal_clamping() {
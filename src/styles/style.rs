@@ -12,6 +12,10 @@ use super::{Alignment, Border, Fill, Font, NumberFormat};
 /// Combines multiple styling components (font, fill, border, alignment,
 /// number format) into a single style that can be applied to cells.
 ///
+/// Styles implement `Eq` and `Hash` consistently, so they can key a
+/// `HashMap` and equal styles share one cached format. Font sizes are
+/// compared and hashed by bit pattern, with NaN sizes normalized.
+///
 /// # Examples
 ///
 /// ```rust,ignore
@@ -22,7 +26,7 @@ use super::{Alignment, Border, Fill, Font, NumberFormat};
 ///     .fill(Fill::solid("#FFFF00"))
 ///     .border(Border::all(BorderStyle::Thin));
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Style {
     /// Font styling
     font: Option<Font>,
@@ -34,6 +38,8 @@ pub struct Style {
     alignment: Option<Alignment>,
     /// Number format styling
     number_format: Option<NumberFormat>,
+    /// Show the value as typed text, Excel's leading apostrophe
+    quote_prefix: bool,
 }
 
 impl Style {
@@ -46,9 +52,35 @@ impl Style {
             border: None,
             alignment: None,
             number_format: None,
+            quote_prefix: false,
         }
     }
 
+    /// Create a style from an Excel number format string
+    ///
+    /// The format is parsed with [`NumberFormat::from_format_string`].
+    /// "General" is Excel's default, so it gives a style without a number
+    /// format.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - Excel format string, e.g. from `Reader::number_formats`
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let style = Style::from_number_format_string("$#,##0.00")
+    ///     .font(Font::new().bold(true));
+    /// ```
+    #[must_use]
+    pub fn from_number_format_string(format: &str) -> Self {
+        let number_format = NumberFormat::from_format_string(format);
+        if number_format == NumberFormat::general() {
+            return Self::new();
+        }
+        Self::new().number_format(number_format)
+    }
+
     /// Set font styling
     ///
     /// # Arguments
@@ -140,6 +172,23 @@ impl Style {
         self
     }
 
+    /// Set whether the cell value is quote-prefixed
+    ///
+    /// Matches typing a leading apostrophe in Excel: a value like
+    /// `=notaformula` or `+44 20` stays text when the cell is edited,
+    /// without switching the cell to the text number format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let style = Style::new().quote_prefix(true);
+    /// ```
+    #[must_use]
+    pub fn quote_prefix(mut self, enable: bool) -> Self {
+        self.quote_prefix = enable;
+        self
+    }
+
     /// Apply all style components to a `rust_xlsxwriter` Format
     ///
     /// # Arguments
@@ -176,6 +225,10 @@ impl Style {
             format = number_format.apply_to_format(format);
         }
 
+        if self.quote_prefix {
+            format = format.set_quote_prefix();
+        }
+
         format
     }
 
@@ -208,6 +261,12 @@ impl Style {
     pub fn get_number_format(&self) -> Option<&NumberFormat> {
         self.number_format.as_ref()
     }
+
+    /// Check if the cell value is quote-prefixed
+    #[must_use]
+    pub fn has_quote_prefix(&self) -> bool {
+        self.quote_prefix
+    }
 }
 
 impl Default for Style {
@@ -291,6 +350,30 @@ mod tests {
         assert_eq!(style_number_format.get_decimals(), Some(2));
     }
 
+    /// TDD RED: Test style quote prefix
+    #[test]
+    fn test_style_quote_prefix() {
+        assert!(!Style::new().has_quote_prefix());
+
+        let style = Style::new().quote_prefix(true);
+        assert!(style.has_quote_prefix());
+        assert!(style.get_number_format().is_none());
+    }
+
+    /// TDD RED: Test rebuilding a bold currency style from its parts
+    #[test]
+    fn test_from_number_format_string() {
+        let style = Style::from_number_format_string("$#,##0.00").font(Font::new().bold(true));
+
+        assert_eq!(
+            style,
+            Style::new()
+                .font(Font::new().bold(true))
+                .number_format(NumberFormat::currency(2))
+        );
+        assert_eq!(Style::from_number_format_string("General"), Style::new());
+    }
+
     /// TDD RED: Test style builder with all components
     #[test]
     fn test_style_builder_complete() {
@@ -343,4 +426,53 @@ mod tests {
         assert!(style2.get_fill().is_some());
         assert_eq!(style1, style2);
     }
+
+    /// TDD RED: Test equal styles hash equally and a changed field differs
+    #[test]
+    fn test_style_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let hash = |style: &Style| {
+            let mut hasher = DefaultHasher::new();
+            style.hash(&mut hasher);
+            hasher.finish()
+        };
+        let style = || {
+            Style::new()
+                .font(Font::new().name("Arial").size(12.0).bold(true))
+                .fill(Fill::solid("#FFFF00"))
+                .border(Border::all(BorderStyle::Thin))
+                .alignment(Alignment::new().horizontal(HorizontalAlignment::Center))
+                .number_format(NumberFormat::currency(2))
+        };
+
+        assert_eq!(style(), style());
+        assert_eq!(hash(&style()), hash(&style()));
+        assert_ne!(
+            hash(&style()),
+            hash(&style().font(Font::new().name("Arial").size(12.5).bold(true)))
+        );
+        assert_ne!(hash(&style()), hash(&style().fill(Fill::solid("#FF0000"))));
+        assert_ne!(hash(&style()), hash(&style().quote_prefix(true)));
+    }
+
+    /// TDD RED: Test styles can key a format cache
+    #[test]
+    fn test_style_hash_map_key() {
+        use std::collections::HashMap;
+
+        // Arrange
+        let bold = || Style::new().font(Font::new().bold(true).size(11.0));
+        let mut cache: HashMap<Style, usize> = HashMap::new();
+
+        // Act: Equal styles land on the same entry
+        cache.insert(bold(), 1);
+        cache.insert(bold(), 2);
+        cache.insert(Style::new().fill(Fill::solid("#FFFF00")), 3);
+
+        // Assert
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&bold()), Some(&2));
+    }
 }
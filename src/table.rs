@@ -0,0 +1,398 @@
+//! Excel Tables (`ListObject`s)
+//!
+//! Provides types for turning a worksheet range into an Excel Table with
+//! named columns, a table style, banded rows and an optional totals row.
+
+use crate::error::{Error, Result};
+use rust_xlsxwriter::{
+    Table as XlsxTable, TableColumn as XlsxTableColumn, TableFunction, TableStyle as XlsxTableStyle,
+};
+
+/// Built-in "Light" table styles, `TableStyle::Light(1)` to `Light(21)`
+const LIGHT_STYLES: [XlsxTableStyle; 21] = [
+    XlsxTableStyle::Light1,
+    XlsxTableStyle::Light2,
+    XlsxTableStyle::Light3,
+    XlsxTableStyle::Light4,
+    XlsxTableStyle::Light5,
+    XlsxTableStyle::Light6,
+    XlsxTableStyle::Light7,
+    XlsxTableStyle::Light8,
+    XlsxTableStyle::Light9,
+    XlsxTableStyle::Light10,
+    XlsxTableStyle::Light11,
+    XlsxTableStyle::Light12,
+    XlsxTableStyle::Light13,
+    XlsxTableStyle::Light14,
+    XlsxTableStyle::Light15,
+    XlsxTableStyle::Light16,
+    XlsxTableStyle::Light17,
+    XlsxTableStyle::Light18,
+    XlsxTableStyle::Light19,
+    XlsxTableStyle::Light20,
+    XlsxTableStyle::Light21,
+];
+
+/// Built-in "Medium" table styles, `TableStyle::Medium(1)` to `Medium(28)`
+const MEDIUM_STYLES: [XlsxTableStyle; 28] = [
+    XlsxTableStyle::Medium1,
+    XlsxTableStyle::Medium2,
+    XlsxTableStyle::Medium3,
+    XlsxTableStyle::Medium4,
+    XlsxTableStyle::Medium5,
+    XlsxTableStyle::Medium6,
+    XlsxTableStyle::Medium7,
+    XlsxTableStyle::Medium8,
+    XlsxTableStyle::Medium9,
+    XlsxTableStyle::Medium10,
+    XlsxTableStyle::Medium11,
+    XlsxTableStyle::Medium12,
+    XlsxTableStyle::Medium13,
+    XlsxTableStyle::Medium14,
+    XlsxTableStyle::Medium15,
+    XlsxTableStyle::Medium16,
+    XlsxTableStyle::Medium17,
+    XlsxTableStyle::Medium18,
+    XlsxTableStyle::Medium19,
+    XlsxTableStyle::Medium20,
+    XlsxTableStyle::Medium21,
+    XlsxTableStyle::Medium22,
+    XlsxTableStyle::Medium23,
+    XlsxTableStyle::Medium24,
+    XlsxTableStyle::Medium25,
+    XlsxTableStyle::Medium26,
+    XlsxTableStyle::Medium27,
+    XlsxTableStyle::Medium28,
+];
+
+/// Built-in "Dark" table styles, `TableStyle::Dark(1)` to `Dark(11)`
+const DARK_STYLES: [XlsxTableStyle; 11] = [
+    XlsxTableStyle::Dark1,
+    XlsxTableStyle::Dark2,
+    XlsxTableStyle::Dark3,
+    XlsxTableStyle::Dark4,
+    XlsxTableStyle::Dark5,
+    XlsxTableStyle::Dark6,
+    XlsxTableStyle::Dark7,
+    XlsxTableStyle::Dark8,
+    XlsxTableStyle::Dark9,
+    XlsxTableStyle::Dark10,
+    XlsxTableStyle::Dark11,
+];
+
+/// Built-in Excel table style
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableStyle {
+    /// No table style
+    None,
+    /// "Table Style Light N" (1-21)
+    Light(u8),
+    /// "Table Style Medium N" (1-28)
+    Medium(u8),
+    /// "Table Style Dark N" (1-11)
+    Dark(u8),
+}
+
+impl TableStyle {
+    /// Convert to the `rust_xlsxwriter` style, or `None` if out of range
+    fn to_xlsx(self) -> Option<XlsxTableStyle> {
+        let (styles, number): (&[XlsxTableStyle], u8) = match self {
+            Self::None => return Some(XlsxTableStyle::None),
+            Self::Light(number) => (&LIGHT_STYLES, number),
+            Self::Medium(number) => (&MEDIUM_STYLES, number),
+            Self::Dark(number) => (&DARK_STYLES, number),
+        };
+        styles.get(usize::from(number).checked_sub(1)?).copied()
+    }
+}
+
+/// Function used in a table's totals row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TotalFunction {
+    /// Average of the column
+    Average,
+    /// Count of non-empty cells
+    Count,
+    /// Count of numeric cells
+    CountNumbers,
+    /// Maximum value
+    Max,
+    /// Minimum value
+    Min,
+    /// Sum of the column
+    Sum,
+    /// Standard deviation
+    StdDev,
+    /// Variance
+    Var,
+}
+
+impl From<TotalFunction> for TableFunction {
+    fn from(function: TotalFunction) -> Self {
+        match function {
+            TotalFunction::Average => TableFunction::Average,
+            TotalFunction::Count => TableFunction::Count,
+            TotalFunction::CountNumbers => TableFunction::CountNumbers,
+            TotalFunction::Max => TableFunction::Max,
+            TotalFunction::Min => TableFunction::Min,
+            TotalFunction::Sum => TableFunction::Sum,
+            TotalFunction::StdDev => TableFunction::StdDev,
+            TotalFunction::Var => TableFunction::Var,
+        }
+    }
+}
+
+/// Table column configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableColumn {
+    /// Header name
+    header: String,
+    /// Totals row function
+    total_function: Option<TotalFunction>,
+    /// Totals row label, used instead of a function
+    total_label: Option<String>,
+}
+
+impl TableColumn {
+    /// Create a new table column with a header name
+    #[must_use]
+    pub fn new(header: impl Into<String>) -> Self {
+        Self {
+            header: header.into(),
+            total_function: None,
+            total_label: None,
+        }
+    }
+
+    /// Set the totals row function for this column
+    #[must_use]
+    pub fn total_function(mut self, function: TotalFunction) -> Self {
+        self.total_function = Some(function);
+        self
+    }
+
+    /// Set a totals row label (e.g., "Total") for this column
+    #[must_use]
+    pub fn total_label(mut self, label: impl Into<String>) -> Self {
+        self.total_label = Some(label.into());
+        self
+    }
+
+    /// Get the header name
+    #[must_use]
+    pub fn get_header(&self) -> &str {
+        &self.header
+    }
+
+    /// Get the totals row function
+    #[must_use]
+    pub fn get_total_function(&self) -> Option<TotalFunction> {
+        self.total_function
+    }
+
+    /// Convert to a `rust_xlsxwriter` table column
+    fn to_xlsx_column(&self) -> XlsxTableColumn {
+        let mut column = XlsxTableColumn::new().set_header(&self.header);
+        if let Some(function) = self.total_function {
+            column = column.set_total_function(function.into());
+        }
+        if let Some(label) = &self.total_label {
+            column = column.set_total_label(label);
+        }
+        column
+    }
+}
+
+/// Excel Table configuration
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use xlsxpress::table::{TableColumn, TableOptions, TableStyle, TotalFunction};
+///
+/// let options = TableOptions::new()
+///     .column(TableColumn::new("Region").total_label("Total"))
+///     .column(TableColumn::new("Sales").total_function(TotalFunction::Sum))
+///     .style(TableStyle::Medium(2))
+///     .total_row(true);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableOptions {
+    /// Column configurations, left to right
+    columns: Vec<TableColumn>,
+    /// Table name used in structured references
+    name: Option<String>,
+    /// Table style
+    style: Option<TableStyle>,
+    /// Show a totals row as the last row of the range
+    total_row: bool,
+    /// Shade alternate rows
+    banded_rows: bool,
+}
+
+impl TableOptions {
+    /// Create new table options with default settings
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            columns: Vec::new(),
+            name: None,
+            style: None,
+            total_row: false,
+            banded_rows: true,
+        }
+    }
+
+    /// Add a column configuration
+    #[must_use]
+    pub fn column(mut self, column: TableColumn) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// Add columns from header names
+    #[must_use]
+    pub fn headers(mut self, headers: &[&str]) -> Self {
+        self.columns
+            .extend(headers.iter().map(|header| TableColumn::new(*header)));
+        self
+    }
+
+    /// Set the table name used in structured references (e.g., `SalesData`)
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the table style
+    #[must_use]
+    pub fn style(mut self, style: TableStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Set whether to show a totals row
+    #[must_use]
+    pub fn total_row(mut self, enable: bool) -> Self {
+        self.total_row = enable;
+        self
+    }
+
+    /// Set whether to shade alternate rows
+    #[must_use]
+    pub fn banded_rows(mut self, enable: bool) -> Self {
+        self.banded_rows = enable;
+        self
+    }
+
+    /// Get the column configurations
+    #[must_use]
+    pub fn get_columns(&self) -> &[TableColumn] {
+        &self.columns
+    }
+
+    /// Get the table style
+    #[must_use]
+    pub fn get_style(&self) -> Option<TableStyle> {
+        self.style
+    }
+
+    /// Check if the totals row is shown
+    #[must_use]
+    pub fn has_total_row(&self) -> bool {
+        self.total_row
+    }
+
+    /// Check if rows are banded
+    #[must_use]
+    pub fn has_banded_rows(&self) -> bool {
+        self.banded_rows
+    }
+
+    /// Convert to a `rust_xlsxwriter` table
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the table style number is out of range.
+    pub(crate) fn to_xlsx_table(&self) -> Result<XlsxTable> {
+        let columns: Vec<XlsxTableColumn> = self
+            .columns
+            .iter()
+            .map(TableColumn::to_xlsx_column)
+            .collect();
+        let mut table = XlsxTable::new()
+            .set_columns(&columns)
+            .set_total_row(self.total_row)
+            .set_banded_rows(self.banded_rows);
+        if let Some(name) = &self.name {
+            table = table.set_name(name);
+        }
+        if let Some(style) = self.style {
+            let xlsx_style = style
+                .to_xlsx()
+                .ok_or_else(|| Error::Other(format!("Unknown table style: {style:?}")))?;
+            table = table.set_style(xlsx_style);
+        }
+        Ok(table)
+    }
+}
+
+impl Default for TableOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test table options defaults
+    #[test]
+    fn test_table_options_new() {
+        let options = TableOptions::new();
+        assert_eq!(options.get_columns(), []);
+        assert_eq!(options.get_style(), None);
+        assert!(!options.has_total_row());
+        assert!(options.has_banded_rows());
+    }
+
+    /// TDD RED: Test table options builder
+    #[test]
+    fn test_table_options_builder() {
+        let options = TableOptions::new()
+            .headers(&["Region", "Quarter"])
+            .column(TableColumn::new("Sales").total_function(TotalFunction::Sum))
+            .style(TableStyle::Light(9))
+            .total_row(true)
+            .banded_rows(false);
+
+        let headers: Vec<&str> = options
+            .get_columns()
+            .iter()
+            .map(TableColumn::get_header)
+            .collect();
+        assert_eq!(headers, vec!["Region", "Quarter", "Sales"]);
+        assert_eq!(
+            options.get_columns()[2].get_total_function(),
+            Some(TotalFunction::Sum)
+        );
+        assert!(options.has_total_row());
+        assert!(!options.has_banded_rows());
+        assert!(options.to_xlsx_table().is_ok());
+    }
+
+    /// TDD RED: Test table style range checks
+    #[test]
+    fn test_table_style_range() {
+        assert!(TableStyle::Light(1).to_xlsx() == Some(XlsxTableStyle::Light1));
+        assert!(TableStyle::Medium(28).to_xlsx() == Some(XlsxTableStyle::Medium28));
+        assert!(TableStyle::Dark(11).to_xlsx() == Some(XlsxTableStyle::Dark11));
+        assert!(TableStyle::Dark(12).to_xlsx().is_none());
+        assert!(TableStyle::Light(0).to_xlsx().is_none());
+
+        let options = TableOptions::new().style(TableStyle::Medium(29));
+        assert!(options.to_xlsx_table().is_err());
+    }
+}
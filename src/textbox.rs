@@ -0,0 +1,129 @@
+//! Worksheet textboxes
+//!
+//! Provides options for textboxes, free-floating shapes holding text that
+//! are drawn over the cells, e.g. to annotate a report.
+
+use crate::styles::Font;
+use rust_xlsxwriter::{Color, Shape, ShapeFormat, ShapeSolidFill};
+
+/// Textbox size, fill and font
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use xlsxpress::styles::Font;
+/// use xlsxpress::textbox::TextboxOptions;
+///
+/// let options = TextboxOptions::new()
+///     .size(240, 60)
+///     .fill_color("#FFF2CC")
+///     .font(Font::new().bold(true).color("#7F6000"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TextboxOptions {
+    /// Width and height in pixels; Excel's default is 192 x 120
+    size: Option<(u32, u32)>,
+    /// Solid background color
+    fill_color: Option<Color>,
+    /// Text font
+    font: Option<Font>,
+}
+
+impl TextboxOptions {
+    /// Create textbox options with Excel's default look
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the textbox size in pixels
+    #[must_use]
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.size = Some((width, height));
+        self
+    }
+
+    /// Set a solid background color
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - Hex color string like "#FF0000" or "FF0000"
+    #[must_use]
+    pub fn fill_color(mut self, color: impl Into<String>) -> Self {
+        let color = color.into();
+        if let Ok(parsed) = u32::from_str_radix(color.trim_start_matches('#'), 16) {
+            self.fill_color = Some(Color::RGB(parsed));
+        }
+        self
+    }
+
+    /// Set the text font
+    #[must_use]
+    pub fn font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Get the textbox size in pixels
+    #[must_use]
+    pub fn get_size(&self) -> Option<(u32, u32)> {
+        self.size
+    }
+
+    /// Check if a background color is set
+    #[must_use]
+    pub fn has_fill_color(&self) -> bool {
+        self.fill_color.is_some()
+    }
+
+    /// Get the text font
+    #[must_use]
+    pub fn get_font(&self) -> Option<&Font> {
+        self.font.as_ref()
+    }
+
+    /// Convert to a `rust_xlsxwriter` textbox shape holding `text`
+    pub(crate) fn to_xlsx_shape(&self, text: &str) -> Shape {
+        let mut shape = Shape::textbox().set_text(text);
+        if let Some((width, height)) = self.size {
+            shape = shape.set_width(width).set_height(height);
+        }
+        if let Some(color) = self.fill_color {
+            let fill = ShapeSolidFill::new().set_color(color);
+            shape = shape.set_format(&ShapeFormat::new().set_solid_fill(&fill));
+        }
+        if let Some(font) = &self.font {
+            shape = shape.set_font(&font.to_shape_font());
+        }
+        shape
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// TDD RED: Test textbox options builder
+    #[test]
+    fn test_textbox_options_builder() {
+        let options = TextboxOptions::new();
+        assert_eq!(options.get_size(), None);
+        assert!(!options.has_fill_color());
+        assert!(options.get_font().is_none());
+
+        let options = TextboxOptions::new()
+            .size(240, 60)
+            .fill_color("#FFF2CC")
+            .font(Font::new().bold(true));
+        assert_eq!(options.get_size(), Some((240, 60)));
+        assert!(options.has_fill_color());
+        assert!(options.get_font().unwrap().is_bold());
+    }
+
+    /// TDD RED: Test an invalid fill color is ignored
+    #[test]
+    fn test_textbox_options_invalid_fill_color() {
+        let options = TextboxOptions::new().fill_color("yellow");
+        assert!(!options.has_fill_color());
+    }
+}
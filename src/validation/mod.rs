@@ -7,5 +7,6 @@ pub mod types;
 // Re-export for convenience
 pub use types::{
     DataValidation, DateValidation, ListValidation, NumberValidation, TextValidation,
-    ValidationError, ValidationErrorStyle, ValidationRule, ValidationWarning,
+    ValidationError, ValidationErrorStyle, ValidationOperator, ValidationRule, ValidationWarning,
+    WholeNumberValidation,
 };
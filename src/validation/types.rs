@@ -3,6 +3,12 @@
 //! Provides comprehensive data validation support for Excel cells including
 //! lists, numbers, dates, text length, and custom formulas.
 
+use crate::error::Result;
+use rust_xlsxwriter::{
+    DataValidation as XlsxDataValidation, DataValidationErrorStyle, DataValidationRule,
+    ExcelDateTime, Formula, IntoDataValidationValue,
+};
+
 /// Validation error style
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum ValidationErrorStyle {
@@ -15,11 +21,23 @@ pub enum ValidationErrorStyle {
     Information,
 }
 
+impl From<ValidationErrorStyle> for DataValidationErrorStyle {
+    fn from(style: ValidationErrorStyle) -> Self {
+        match style {
+            ValidationErrorStyle::Stop => DataValidationErrorStyle::Stop,
+            ValidationErrorStyle::Warning => DataValidationErrorStyle::Warning,
+            ValidationErrorStyle::Information => DataValidationErrorStyle::Information,
+        }
+    }
+}
+
 /// List validation configuration
 #[derive(Debug, Clone, PartialEq)]
 pub struct ListValidation {
     /// List of allowed values
     values: Vec<String>,
+    /// Range reference holding the allowed values (e.g., "Sheet2!$A$1:$A$100")
+    range: Option<String>,
     /// Show dropdown in cell
     show_dropdown: bool,
 }
@@ -30,6 +48,24 @@ impl ListValidation {
     pub fn new(values: Vec<String>) -> Self {
         Self {
             values,
+            range: None,
+            show_dropdown: true,
+        }
+    }
+
+    /// Create a list validation sourced from a worksheet range
+    ///
+    /// Use this for lists too long to store inline.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference` - Range reference like "Sheet2!$A$1:$A$100", with or
+    ///   without a leading `=`
+    #[must_use]
+    pub fn from_range(reference: &str) -> Self {
+        Self {
+            values: Vec::new(),
+            range: Some(reference.trim_start_matches('=').to_string()),
             show_dropdown: true,
         }
     }
@@ -47,6 +83,12 @@ impl ListValidation {
         &self.values
     }
 
+    /// Get the source range reference, if the list is range based
+    #[must_use]
+    pub fn get_range(&self) -> Option<&str> {
+        self.range.as_deref()
+    }
+
     /// Check if dropdown is shown
     #[must_use]
     pub fn is_dropdown_shown(&self) -> bool {
@@ -54,12 +96,36 @@ impl ListValidation {
     }
 }
 
-/// Number validation configuration
+/// Comparison operator for number validations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ValidationOperator {
+    /// Between min and max (inclusive)
+    #[default]
+    Between,
+    /// Outside min and max
+    NotBetween,
+    /// Equal to a value
+    Equal,
+    /// Not equal to a value
+    NotEqual,
+    /// Greater than a value
+    GreaterThan,
+    /// Greater than or equal to a value
+    GreaterThanOrEqual,
+    /// Less than a value
+    LessThan,
+    /// Less than or equal to a value
+    LessThanOrEqual,
+}
+
+/// Decimal number validation configuration
 #[derive(Debug, Clone, PartialEq)]
 pub struct NumberValidation {
-    /// Minimum value (inclusive)
+    /// Comparison operator
+    operator: ValidationOperator,
+    /// Lower bound, or the compared value for single-value operators
     min: Option<f64>,
-    /// Maximum value (inclusive)
+    /// Upper bound, or the compared value for single-value operators
     max: Option<f64>,
 }
 
@@ -67,28 +133,59 @@ impl NumberValidation {
     /// Create a new number validation with range
     #[must_use]
     pub fn range(min: f64, max: f64) -> Self {
-        Self {
-            min: Some(min),
-            max: Some(max),
-        }
+        Self::with_operator(ValidationOperator::Between, Some(min), Some(max))
+    }
+
+    /// Create a validation for values outside a range
+    #[must_use]
+    pub fn not_between(min: f64, max: f64) -> Self {
+        Self::with_operator(ValidationOperator::NotBetween, Some(min), Some(max))
     }
 
     /// Create a validation for minimum value only
     #[must_use]
     pub fn min(min: f64) -> Self {
-        Self {
-            min: Some(min),
-            max: None,
-        }
+        Self::with_operator(ValidationOperator::GreaterThanOrEqual, Some(min), None)
     }
 
     /// Create a validation for maximum value only
     #[must_use]
     pub fn max(max: f64) -> Self {
-        Self {
-            min: None,
-            max: Some(max),
-        }
+        Self::with_operator(ValidationOperator::LessThanOrEqual, None, Some(max))
+    }
+
+    /// Create a validation for values greater than `value`
+    #[must_use]
+    pub fn greater_than(value: f64) -> Self {
+        Self::with_operator(ValidationOperator::GreaterThan, Some(value), None)
+    }
+
+    /// Create a validation for values less than `value`
+    #[must_use]
+    pub fn less_than(value: f64) -> Self {
+        Self::with_operator(ValidationOperator::LessThan, None, Some(value))
+    }
+
+    /// Create a validation for values equal to `value`
+    #[must_use]
+    pub fn equal_to(value: f64) -> Self {
+        Self::with_operator(ValidationOperator::Equal, Some(value), Some(value))
+    }
+
+    /// Create a validation for values not equal to `value`
+    #[must_use]
+    pub fn not_equal_to(value: f64) -> Self {
+        Self::with_operator(ValidationOperator::NotEqual, Some(value), Some(value))
+    }
+
+    fn with_operator(operator: ValidationOperator, min: Option<f64>, max: Option<f64>) -> Self {
+        Self { operator, min, max }
+    }
+
+    /// Get the comparison operator
+    #[must_use]
+    pub fn get_operator(&self) -> ValidationOperator {
+        self.operator
     }
 
     /// Get the minimum value
@@ -104,6 +201,89 @@ impl NumberValidation {
     }
 }
 
+/// Whole number validation configuration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WholeNumberValidation {
+    /// Comparison operator
+    operator: ValidationOperator,
+    /// Lower bound, or the compared value for single-value operators
+    min: Option<i32>,
+    /// Upper bound, or the compared value for single-value operators
+    max: Option<i32>,
+}
+
+impl WholeNumberValidation {
+    /// Create a new whole number validation with range
+    #[must_use]
+    pub fn range(min: i32, max: i32) -> Self {
+        Self::with_operator(ValidationOperator::Between, Some(min), Some(max))
+    }
+
+    /// Create a validation for values outside a range
+    #[must_use]
+    pub fn not_between(min: i32, max: i32) -> Self {
+        Self::with_operator(ValidationOperator::NotBetween, Some(min), Some(max))
+    }
+
+    /// Create a validation for minimum value only
+    #[must_use]
+    pub fn min(min: i32) -> Self {
+        Self::with_operator(ValidationOperator::GreaterThanOrEqual, Some(min), None)
+    }
+
+    /// Create a validation for maximum value only
+    #[must_use]
+    pub fn max(max: i32) -> Self {
+        Self::with_operator(ValidationOperator::LessThanOrEqual, None, Some(max))
+    }
+
+    /// Create a validation for values greater than `value`
+    #[must_use]
+    pub fn greater_than(value: i32) -> Self {
+        Self::with_operator(ValidationOperator::GreaterThan, Some(value), None)
+    }
+
+    /// Create a validation for values less than `value`
+    #[must_use]
+    pub fn less_than(value: i32) -> Self {
+        Self::with_operator(ValidationOperator::LessThan, None, Some(value))
+    }
+
+    /// Create a validation for values equal to `value`
+    #[must_use]
+    pub fn equal_to(value: i32) -> Self {
+        Self::with_operator(ValidationOperator::Equal, Some(value), Some(value))
+    }
+
+    /// Create a validation for values not equal to `value`
+    #[must_use]
+    pub fn not_equal_to(value: i32) -> Self {
+        Self::with_operator(ValidationOperator::NotEqual, Some(value), Some(value))
+    }
+
+    fn with_operator(operator: ValidationOperator, min: Option<i32>, max: Option<i32>) -> Self {
+        Self { operator, min, max }
+    }
+
+    /// Get the comparison operator
+    #[must_use]
+    pub fn get_operator(&self) -> ValidationOperator {
+        self.operator
+    }
+
+    /// Get the minimum value
+    #[must_use]
+    pub fn get_min(&self) -> Option<i32> {
+        self.min
+    }
+
+    /// Get the maximum value
+    #[must_use]
+    pub fn get_max(&self) -> Option<i32> {
+        self.max
+    }
+}
+
 /// Date validation configuration
 #[derive(Debug, Clone, PartialEq)]
 pub struct DateValidation {
@@ -209,8 +389,10 @@ impl TextValidation {
 pub enum ValidationRule {
     /// List validation (dropdown)
     List(ListValidation),
-    /// Number range validation
+    /// Decimal number validation
     Number(NumberValidation),
+    /// Whole number validation
+    WholeNumber(WholeNumberValidation),
     /// Date range validation
     Date(DateValidation),
     /// Text length validation
@@ -219,6 +401,89 @@ pub enum ValidationRule {
     Custom(String),
 }
 
+impl ValidationRule {
+    /// Apply the rule to a `rust_xlsxwriter` data validation
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the list values or dates exceed Excel limits.
+    #[allow(clippy::cast_possible_truncation)]
+    fn apply_to_validation(&self, validation: XlsxDataValidation) -> Result<XlsxDataValidation> {
+        let validation = match self {
+            Self::List(list) => match &list.range {
+                Some(range) => validation.allow_list_formula(Formula::new(format!("={range}"))),
+                None => validation.allow_list_strings(list.get_values())?,
+            }
+            .show_dropdown(list.is_dropdown_shown()),
+            Self::Number(number) => match operator_rule(number.operator, number.min, number.max) {
+                Some(rule) => validation.allow_decimal_number(rule),
+                None => validation.allow_any_value(),
+            },
+            Self::WholeNumber(number) => {
+                match operator_rule(number.operator, number.min, number.max) {
+                    Some(rule) => validation.allow_whole_number(rule),
+                    None => validation.allow_any_value(),
+                }
+            }
+            Self::Date(date) => {
+                let min = date
+                    .min
+                    .map(ExcelDateTime::from_serial_datetime)
+                    .transpose()?;
+                let max = date
+                    .max
+                    .map(ExcelDateTime::from_serial_datetime)
+                    .transpose()?;
+                match bounds_rule(min, max) {
+                    Some(rule) => validation.allow_date(rule),
+                    None => validation.allow_any_value(),
+                }
+            }
+            Self::Text(text) => {
+                let min = text.min_length.map(|len| len as u32);
+                let max = text.max_length.map(|len| len as u32);
+                match bounds_rule(min, max) {
+                    Some(rule) => validation.allow_text_length(rule),
+                    None => validation.allow_any_value(),
+                }
+            }
+            Self::Custom(formula) => validation.allow_custom(Formula::new(formula)),
+        };
+        Ok(validation)
+    }
+}
+
+/// Build an inclusive rule from optional lower and upper bounds
+fn bounds_rule<T: IntoDataValidationValue>(
+    min: Option<T>,
+    max: Option<T>,
+) -> Option<DataValidationRule<T>> {
+    match (min, max) {
+        (Some(min), Some(max)) => Some(DataValidationRule::Between(min, max)),
+        (Some(min), None) => Some(DataValidationRule::GreaterThanOrEqualTo(min)),
+        (None, Some(max)) => Some(DataValidationRule::LessThanOrEqualTo(max)),
+        (None, None) => None,
+    }
+}
+
+/// Build a rule for `operator` from its lower and upper values
+fn operator_rule<T: IntoDataValidationValue>(
+    operator: ValidationOperator,
+    min: Option<T>,
+    max: Option<T>,
+) -> Option<DataValidationRule<T>> {
+    match operator {
+        ValidationOperator::Between => Some(DataValidationRule::Between(min?, max?)),
+        ValidationOperator::NotBetween => Some(DataValidationRule::NotBetween(min?, max?)),
+        ValidationOperator::Equal => min.map(DataValidationRule::EqualTo),
+        ValidationOperator::NotEqual => min.map(DataValidationRule::NotEqualTo),
+        ValidationOperator::GreaterThan => min.map(DataValidationRule::GreaterThan),
+        ValidationOperator::GreaterThanOrEqual => min.map(DataValidationRule::GreaterThanOrEqualTo),
+        ValidationOperator::LessThan => max.map(DataValidationRule::LessThan),
+        ValidationOperator::LessThanOrEqual => max.map(DataValidationRule::LessThanOrEqualTo),
+    }
+}
+
 /// Validation error configuration
 #[derive(Debug, Clone, PartialEq)]
 pub struct ValidationError {
@@ -272,6 +537,22 @@ impl ValidationError {
     pub fn get_message(&self) -> Option<&str> {
         self.message.as_deref()
     }
+
+    /// Apply error settings to a `rust_xlsxwriter` data validation
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the title or message exceed Excel length limits.
+    fn apply_to_validation(&self, validation: XlsxDataValidation) -> Result<XlsxDataValidation> {
+        let mut validation = validation.set_error_style(self.style.into());
+        if let Some(title) = &self.title {
+            validation = validation.set_error_title(title)?;
+        }
+        if let Some(message) = &self.message {
+            validation = validation.set_error_message(message)?;
+        }
+        Ok(validation)
+    }
 }
 
 impl Default for ValidationError {
@@ -324,6 +605,22 @@ impl ValidationWarning {
     pub fn get_message(&self) -> Option<&str> {
         self.message.as_deref()
     }
+
+    /// Apply input message settings to a `rust_xlsxwriter` data validation
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the title or message exceed Excel length limits.
+    fn apply_to_validation(&self, validation: XlsxDataValidation) -> Result<XlsxDataValidation> {
+        let mut validation = validation;
+        if let Some(title) = &self.title {
+            validation = validation.set_input_title(title)?;
+        }
+        if let Some(message) = &self.message {
+            validation = validation.set_input_message(message)?;
+        }
+        Ok(validation)
+    }
 }
 
 impl Default for ValidationWarning {
@@ -357,6 +654,8 @@ pub struct DataValidation {
     error: ValidationError,
     /// Input warning configuration
     warning: Option<ValidationWarning>,
+    /// Show the input prompt when the cell is selected
+    show_input_message: bool,
     /// Ignore blank cells
     ignore_blank: bool,
 }
@@ -369,6 +668,7 @@ impl DataValidation {
             rule,
             error: ValidationError::default(),
             warning: None,
+            show_input_message: true,
             ignore_blank: true,
         }
     }
@@ -381,12 +681,37 @@ impl DataValidation {
     }
 
     /// Set input warning
+    ///
+    /// Despite the name this is the input prompt shown when the cell is
+    /// selected, not an error alert. Prefer [`DataValidation::input_message`].
     #[must_use]
     pub fn warning(mut self, warning: ValidationWarning) -> Self {
         self.warning = Some(warning);
         self
     }
 
+    /// Set the input prompt shown when the cell is selected
+    ///
+    /// This is separate from the error alert configured with
+    /// [`DataValidation::error`].
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Prompt title (max 32 characters)
+    /// * `body` - Prompt text (max 255 characters)
+    #[must_use]
+    pub fn input_message(mut self, title: impl Into<String>, body: impl Into<String>) -> Self {
+        self.warning = Some(ValidationWarning::new().title(title).message(body));
+        self
+    }
+
+    /// Set whether to show the input prompt
+    #[must_use]
+    pub fn show_input_message(mut self, show: bool) -> Self {
+        self.show_input_message = show;
+        self
+    }
+
     /// Set whether to ignore blank cells
     #[must_use]
     pub fn ignore_blank(mut self, ignore: bool) -> Self {
@@ -412,11 +737,36 @@ impl DataValidation {
         self.warning.as_ref()
     }
 
+    /// Check if the input prompt is shown
+    #[must_use]
+    pub fn is_input_message_shown(&self) -> bool {
+        self.show_input_message
+    }
+
     /// Check if blank cells are ignored
     #[must_use]
     pub fn is_blank_ignored(&self) -> bool {
         self.ignore_blank
     }
+
+    /// Convert to a `rust_xlsxwriter` data validation
+    ///
+    /// # Errors
+    ///
+    /// Returns error if list values, dates, titles or messages exceed Excel
+    /// limits.
+    pub(crate) fn to_xlsx_validation(&self) -> Result<XlsxDataValidation> {
+        let validation = self.rule.apply_to_validation(XlsxDataValidation::new())?;
+        let mut validation = self
+            .error
+            .apply_to_validation(validation)?
+            .ignore_blank(self.ignore_blank)
+            .show_input_message(self.show_input_message);
+        if let Some(warning) = &self.warning {
+            validation = warning.apply_to_validation(validation)?;
+        }
+        Ok(validation)
+    }
 }
 
 #[cfg(test)]
@@ -438,6 +788,18 @@ mod tests {
         assert!(!validation.is_dropdown_shown());
     }
 
+    /// TDD RED: Test list validation from a range
+    #[test]
+    fn test_list_validation_from_range() {
+        let validation = ListValidation::from_range("=Sheet2!$A$1:$A$100");
+        assert_eq!(validation.get_range(), Some("Sheet2!$A$1:$A$100"));
+        assert_eq!(validation.get_values(), Vec::<String>::new());
+        assert!(validation.is_dropdown_shown());
+
+        let validation = ListValidation::new(vec!["A".to_string()]);
+        assert_eq!(validation.get_range(), None);
+    }
+
     /// TDD RED: Test number validation range
     #[test]
     fn test_number_validation_range() {
@@ -462,6 +824,34 @@ mod tests {
         assert_eq!(validation.get_max(), Some(50.0));
     }
 
+    /// TDD RED: Test number validation operators
+    #[test]
+    fn test_number_validation_operators() {
+        let validation = NumberValidation::range(0.0, 1.0);
+        assert_eq!(validation.get_operator(), ValidationOperator::Between);
+
+        let validation = NumberValidation::greater_than(5.0);
+        assert_eq!(validation.get_operator(), ValidationOperator::GreaterThan);
+        assert_eq!(validation.get_min(), Some(5.0));
+
+        let validation = NumberValidation::equal_to(3.0);
+        assert_eq!(validation.get_operator(), ValidationOperator::Equal);
+        assert_eq!(validation.get_max(), Some(3.0));
+    }
+
+    /// TDD RED: Test whole number validation
+    #[test]
+    fn test_whole_number_validation() {
+        let validation = WholeNumberValidation::greater_than(0);
+        assert_eq!(validation.get_operator(), ValidationOperator::GreaterThan);
+        assert_eq!(validation.get_min(), Some(0));
+        assert_eq!(validation.get_max(), None);
+
+        let validation = WholeNumberValidation::not_between(1, 10);
+        assert_eq!(validation.get_operator(), ValidationOperator::NotBetween);
+        assert_eq!(validation.get_max(), Some(10));
+    }
+
     /// TDD RED: Test date validation range
     #[test]
     fn test_date_validation_range() {
@@ -545,6 +935,59 @@ mod tests {
         assert_eq!(validation.get_warning().unwrap().get_title(), Some("Info"));
     }
 
+    /// TDD RED: Test input prompt is separate from the error alert
+    #[test]
+    fn test_data_validation_input_message() {
+        let list = ListValidation::new(vec!["Yes".to_string()]);
+        let validation = DataValidation::new(ValidationRule::List(list))
+            .input_message("Choose", "Pick yes")
+            .error(ValidationError::new(ValidationErrorStyle::Stop).title("Invalid"));
+
+        let prompt = validation.get_warning().unwrap();
+        assert_eq!(prompt.get_title(), Some("Choose"));
+        assert_eq!(prompt.get_message(), Some("Pick yes"));
+        assert_eq!(validation.get_error().get_title(), Some("Invalid"));
+        assert!(validation.is_input_message_shown());
+        assert!(!validation
+            .show_input_message(false)
+            .is_input_message_shown());
+    }
+
+    /// TDD RED: Test converting validations to `rust_xlsxwriter`
+    #[test]
+    fn test_to_xlsx_validation() {
+        let rules = vec![
+            ValidationRule::List(ListValidation::new(vec!["Yes".to_string()])),
+            ValidationRule::List(ListValidation::from_range("Sheet2!$A$1:$A$3")),
+            ValidationRule::Number(NumberValidation::min(0.0)),
+            ValidationRule::Number(NumberValidation::not_equal_to(1.5)),
+            ValidationRule::WholeNumber(WholeNumberValidation::greater_than(0)),
+            ValidationRule::Date(DateValidation::range(44197.0, 44927.0)),
+            ValidationRule::Text(TextValidation::max_length(10)),
+            ValidationRule::Custom("=A1>0".to_string()),
+        ];
+
+        for rule in rules {
+            let validation = DataValidation::new(rule.clone())
+                .error(ValidationError::new(ValidationErrorStyle::Warning).title("Error"))
+                .warning(ValidationWarning::new().message("Help"));
+            assert!(
+                validation.to_xlsx_validation().is_ok(),
+                "Failed to convert {rule:?}"
+            );
+        }
+    }
+
+    /// TDD RED: Test that an over-long error title fails conversion
+    #[test]
+    fn test_to_xlsx_validation_title_too_long() {
+        let list = ListValidation::new(vec!["Yes".to_string()]);
+        let error = ValidationError::new(ValidationErrorStyle::Stop).title("x".repeat(100));
+        let validation = DataValidation::new(ValidationRule::List(list)).error(error);
+
+        assert!(validation.to_xlsx_validation().is_err());
+    }
+
     /// TDD RED: Test validation error default
     #[test]
     fn test_validation_error_default() {
@@ -12,6 +12,12 @@ def test_reader_open():
     assert reader is not None
 
 
+def test_reader_open_missing_file():
+    """Test that opening a missing file raises FileNotFoundError"""
+    with pytest.raises(FileNotFoundError):
+        xlsxpress.Reader.open("tests/fixtures/does_not_exist.xlsx")
+
+
 def test_reader_sheet_names():
     """Test getting sheet names"""
     reader = xlsxpress.Reader.open("tests/fixtures/test.xlsx")
@@ -60,6 +66,27 @@ def test_worksheet_get_number():
     assert value == 42.0
 
 
+def test_worksheet_get_cell_types(tmp_path):
+    """Test reading cells as native Python types"""
+    writer = xlsxpress.Writer()
+    sheet = writer.add_worksheet("Sheet1")
+    writer.write_string(sheet, 0, 0, "Hello")
+    writer.write_number(sheet, 0, 1, 42.0)
+    writer.write_boolean(sheet, 0, 2, True)
+    writer.write_boolean(sheet, 0, 3, False)
+    path = tmp_path / "types.xlsx"
+    writer.save(str(path))
+
+    worksheet = xlsxpress.Reader.open(str(path)).worksheet("Sheet1")
+
+    assert worksheet.get_cell(0, 0) == "Hello"
+    assert worksheet.get_cell(0, 1) == 42.0
+    assert isinstance(worksheet.get_cell(0, 1), float)
+    assert worksheet.get_cell(0, 2) is True
+    assert worksheet.get_cell(0, 3) is False
+    assert worksheet.get_cell(5, 5) is None
+
+
 def test_worksheet_to_list():
     """Test converting worksheet to list"""
     reader = xlsxpress.Reader.open("tests/fixtures/test.xlsx")
@@ -76,6 +103,52 @@ def test_worksheet_to_list():
     assert data[0][1] == "42"
 
 
+def test_worksheet_to_records_and_columns(tmp_path):
+    """Test converting worksheet to records and columns with native types"""
+    writer = xlsxpress.Writer()
+    sheet = writer.add_worksheet("Sheet1")
+    writer.write_string(sheet, 0, 0, "name")
+    writer.write_string(sheet, 0, 1, "score")
+    for row, (name, score) in enumerate([("alice", 90), ("bob", 75)], start=1):
+        writer.write_string(sheet, row, 0, name)
+        writer.write_number(sheet, row, 1, score)
+    path = tmp_path / "records.xlsx"
+    writer.save(str(path))
+
+    worksheet = xlsxpress.Reader.open(str(path)).worksheet("Sheet1")
+
+    records = worksheet.to_records()
+    assert records == [
+        {"name": "alice", "score": 90.0},
+        {"name": "bob", "score": 75.0},
+    ]
+    assert all(isinstance(r["score"], float) for r in records)
+
+    columns = worksheet.to_columns()
+    assert columns[0] == ["name", "alice", "bob"]
+    assert columns[1] == ["score", 90.0, 75.0]
+    assert all(isinstance(v, float) for v in columns[1][1:])
+
+
+def test_worksheet_to_records_repeated_and_blank_headers(tmp_path):
+    """Test repeated and blank headers keep every column in records"""
+    writer = xlsxpress.Writer()
+    sheet = writer.add_worksheet("Sheet1")
+    writer.write_string(sheet, 0, 0, "name")
+    writer.write_string(sheet, 0, 1, "name")
+    writer.write_string(sheet, 0, 3, "score")
+    for col, value in enumerate(["alice", "smith", "x", "90"]):
+        writer.write_string(sheet, 1, col, value)
+    path = tmp_path / "repeated.xlsx"
+    writer.save(str(path))
+
+    worksheet = xlsxpress.Reader.open(str(path)).worksheet("Sheet1")
+
+    assert worksheet.to_records() == [
+        {"name": "alice", "name_2": "smith", "col_2": "x", "score": "90"},
+    ]
+
+
 def test_worksheet_iteration():
     """Test iterating over worksheet rows"""
     reader = xlsxpress.Reader.open("tests/fixtures/test.xlsx")
@@ -91,12 +164,6 @@ def test_worksheet_iteration():
     assert rows[0][0] == "Hello"
 
 
-def test_reader_nonexistent_file():
-    """Test that opening nonexistent file raises error"""
-    with pytest.raises(ValueError):
-        xlsxpress.Reader.open("nonexistent.xlsx")
-
-
 def test_reader_nonexistent_sheet():
     """Test that accessing nonexistent sheet raises error"""
     reader = xlsxpress.Reader.open("tests/fixtures/test.xlsx")
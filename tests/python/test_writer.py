@@ -0,0 +1,155 @@
+"""
+Smoke tests for XlsXpress Writer Python bindings
+"""
+
+import zipfile
+
+import pytest
+import xlsxpress
+
+
+def test_write_string_with_style(tmp_path):
+    """Test writing a bold string"""
+    writer = xlsxpress.Writer()
+    sheet = writer.add_worksheet("Sheet1")
+
+    style = xlsxpress.Style().font(xlsxpress.Font().bold(True).size(14.0))
+    writer.write_string_with_style(sheet, 0, 0, "Bold Text", style)
+
+    path = tmp_path / "styled.xlsx"
+    writer.save(str(path))
+
+    reader = xlsxpress.Reader.open(str(path))
+    ws = reader.worksheet("Sheet1")
+    assert ws.get_value(0, 0) == "Bold Text"
+
+
+def test_write_number_with_style(tmp_path):
+    """Test writing a number with a currency format"""
+    writer = xlsxpress.Writer()
+    sheet = writer.add_worksheet("Sheet1")
+
+    style = xlsxpress.Style().number_format(xlsxpress.NumberFormat.currency(2))
+    writer.write_number_with_style(sheet, 0, 0, 1234.56, style)
+
+    path = tmp_path / "styled_number.xlsx"
+    writer.save(str(path))
+
+    reader = xlsxpress.Reader.open(str(path))
+    ws = reader.worksheet("Sheet1")
+    assert ws.get_number(0, 0) == 1234.56
+
+
+def test_insert_line_chart(tmp_path):
+    """Test inserting a line chart"""
+    writer = xlsxpress.Writer()
+    sheet = writer.add_worksheet("Sheet1")
+
+    for row, value in enumerate([10.0, 20.0, 15.0, 30.0]):
+        writer.write_number(sheet, row, 0, value)
+
+    series = xlsxpress.DataSeries("Sheet1!$A$1:$A$4").name("Sales")
+    chart = (
+        xlsxpress.LineChart()
+        .title("Monthly Sales")
+        .add_series(series)
+        .position(xlsxpress.ChartPosition(1, 3))
+    )
+    writer.insert_line_chart(sheet, chart)
+
+    path = tmp_path / "chart.xlsx"
+    writer.save(str(path))
+
+    reader = xlsxpress.Reader.open(str(path))
+    assert reader.worksheet("Sheet1").get_number(3, 0) == 30.0
+
+
+def test_insert_chart_default_position(tmp_path):
+    """Test chart without a position is placed beside the data"""
+    writer = xlsxpress.Writer()
+    sheet = writer.add_worksheet("Sheet1")
+
+    for row, value in enumerate([10.0, 20.0, 15.0]):
+        writer.write_number(sheet, row, 0, value)
+        writer.write_number(sheet, row, 1, value * 2)
+
+    series = xlsxpress.DataSeries("Sheet1!$B$1:$B$3")
+    placement = writer.insert_column_chart(sheet, xlsxpress.ColumnChart().add_series(series))
+
+    assert placement == (0, 3)
+
+
+def test_add_dropdown_validation(tmp_path):
+    """Test adding a dropdown list validation"""
+    writer = xlsxpress.Writer()
+    sheet = writer.add_worksheet("Sheet1")
+
+    dropdown = xlsxpress.DataValidation.list(
+        xlsxpress.ListValidation(["Yes", "No", "Maybe"])
+    ).error(xlsxpress.ValidationError(0).title("Invalid").message("Pick from the list"))
+    writer.add_data_validation(sheet, 0, 0, 9, 0, dropdown)
+    writer.write_string(sheet, 0, 0, "Yes")
+
+    path = tmp_path / "validation.xlsx"
+    writer.save(str(path))
+
+    reader = xlsxpress.Reader.open(str(path))
+    assert reader.worksheet("Sheet1").get_value(0, 0) == "Yes"
+
+
+def test_add_dropdown_from_range(tmp_path):
+    """Test adding a dropdown sourced from a worksheet range"""
+    writer = xlsxpress.Writer()
+    sheet = writer.add_worksheet("Sheet1")
+    options = writer.add_worksheet("Options")
+    for row, option in enumerate(["Low", "Medium", "High"]):
+        writer.write_string(options, row, 0, option)
+
+    dropdown = xlsxpress.DataValidation.list(
+        xlsxpress.ListValidation.from_range("Options!$A$1:$A$3")
+    )
+    writer.add_data_validation(sheet, 0, 0, 9, 0, dropdown)
+
+    path = tmp_path / "range_validation.xlsx"
+    writer.save(str(path))
+
+    with zipfile.ZipFile(path) as package:
+        sheet_xml = package.read("xl/worksheets/sheet1.xml").decode()
+    assert 'type="list"' in sheet_xml
+    assert 'sqref="A1:A10"' in sheet_xml
+    assert "<formula1>Options!$A$1:$A$3</formula1>" in sheet_xml
+
+
+def test_context_manager_saves_on_exit(tmp_path):
+    """Test that the context manager saves to the configured path"""
+    path = tmp_path / "context.xlsx"
+
+    with xlsxpress.Writer() as writer:
+        writer.set_path(str(path))
+        sheet = writer.add_worksheet("Sheet1")
+        writer.write_string(sheet, 0, 0, "Saved")
+
+    reader = xlsxpress.Reader.open(str(path))
+    assert reader.worksheet("Sheet1").get_value(0, 0) == "Saved"
+
+
+def test_context_manager_without_path_raises():
+    """Test that leaving the context manager unsaved raises"""
+    with pytest.raises(ValueError):
+        with xlsxpress.Writer() as writer:
+            writer.add_worksheet("Sheet1")
+
+
+def test_context_manager_after_explicit_save(tmp_path):
+    """Test that an explicit save() satisfies the context manager"""
+    path = tmp_path / "explicit.xlsx"
+
+    with xlsxpress.Writer() as writer:
+        writer.add_worksheet("Sheet1")
+        writer.save(str(path))
+
+    assert path.exists()
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])